@@ -1,12 +1,24 @@
 use clap::Parser;
+use std::time::Duration;
+
+use dnspod_lib::credential::{
+    ChainProvider, Credential, EnvProvider, MetadataProvider, ProfileProvider,
+};
+use dnspod_lib::transport::ReqwestTransport;
 
 #[derive(Debug, Parser)]
 #[clap(version, about)]
 pub struct Args {
     #[arg(long, env = "DNSPOD_SECRET_ID")]
-    pub secret_id: String,
+    pub secret_id: Option<String>,
     #[arg(long, env = "DNSPOD_SECRET_KEY")]
-    pub secret_key: String,
+    pub secret_key: Option<String>,
+    /// 未指定 secret-id/secret-key 时, 使用 ~/.tencentcloud/credentials 中的 profile
+    #[arg(long, env = "TENCENTCLOUD_PROFILE", default_value = "default")]
+    pub profile: String,
+    /// API 地址, 比如国际站 https://dnspod.intl.tencentcloudapi.com
+    #[arg(long, env = "DNSPOD_ENDPOINT")]
+    pub endpoint: Option<String>,
     #[arg(short, long)]
     pub domain: String,
     #[arg(short, long, default_value = "")]
@@ -21,3 +33,26 @@ impl Args {
         Self::parse()
     }
 }
+
+/// 依次尝试: 命令行参数, 环境变量, ~/.tencentcloud/credentials, CVM 实例绑定的角色
+pub fn credential_provider(
+    secret_id: Option<String>,
+    secret_key: Option<String>,
+    profile: String,
+) -> ChainProvider {
+    let chain = match (secret_id, secret_key) {
+        (Some(secret_id), Some(secret_key)) => {
+            ChainProvider::new().with(Credential::new(secret_id, secret_key))
+        }
+        _ => ChainProvider::new(),
+    };
+    // 不在 CVM 上运行时, 元数据服务无法访问, 尽快失败
+    let metadata_client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(2))
+        .build()
+        .unwrap_or_default();
+    chain
+        .with(EnvProvider::default())
+        .with(ProfileProvider::new(profile))
+        .with(MetadataProvider::new(ReqwestTransport::new(metadata_client)))
+}
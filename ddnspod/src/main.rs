@@ -3,41 +3,61 @@
 mod args;
 mod utils;
 
-use anyhow::Result;
+use std::net::IpAddr;
 
 use args::Args;
+use dnspod_lib::data_types::RecordType;
 use dnspod_lib::prelude::*;
-use dnspod_lib::response::{RecordListItem, Response};
+use dnspod_lib::response::RecordListItem;
+use dnspod_lib::transport::ReqwestTransport;
 
-#[allow(non_snake_case)]
 fn main() -> anyhow::Result<()> {
+    run(Args::parse_args())
+}
+
+#[allow(non_snake_case)]
+fn run(args: Args) -> anyhow::Result<()> {
     let Args {
         secret_id,
         secret_key,
+        profile,
+        endpoint,
         domain,
         subdomain,
         value,
-    } = Args::parse_args();
+    } = args;
 
-    let value = value.unwrap_or(utils::get_public_ip()?);
+    let value = match value {
+        Some(value) => value,
+        None => utils::get_public_ip()?,
+    };
     let subdomain = if subdomain.is_empty() {
         None
     } else {
         Some(subdomain)
     };
 
-    let client = Client::new(secret_id, secret_key);
+    let provider = args::credential_provider(secret_id, secret_key, profile);
+    let mut client = Client::with_provider(provider, ReqwestTransport::default());
+    if let Some(endpoint) = endpoint {
+        client = client.with_endpoint(endpoint);
+    }
 
+    // 只更新与 IP 版本对应的 A 或 AAAA 记录
+    let record_type = match value.parse::<IpAddr>() {
+        Ok(IpAddr::V4(_)) => Some(RecordType::A),
+        Ok(IpAddr::V6(_)) => Some(RecordType::AAAA),
+        Err(_) => None,
+    };
     let res = client.execute(DescribeRecordList {
         Domain: domain.clone(),
         Subdomain: subdomain,
+        RecordType: record_type,
         Keyword: None,
+        ..Default::default()
     })?;
 
-    let record_list = res
-        .Response
-        .RecordList
-        .ok_or(anyhow::anyhow!("No record list returned!"))?;
+    let record_list = res.RecordList;
     if record_list.is_empty() {
         return Err(anyhow::anyhow!("record list is empty!"));
     }
@@ -68,40 +88,60 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-struct Client {
-    secret_id: String,
-    secret_key: String,
-    client: reqwest::blocking::Client,
-}
-
-impl Client {
-    pub fn new(secret_id: String, secret_key: String) -> Self {
-        Self {
-            secret_id,
-            secret_key,
-            client: reqwest::blocking::Client::new(),
-        }
-    }
-    pub fn execute(&self, request: impl ExtractCommonParams) -> Result<Response> {
-        let secret_id = self.secret_id.as_str();
-        let secret_key = self.secret_key.as_str();
-
-        let client = &self.client;
-
-        let url = request.url();
-        let body = request.body();
-        let headers = request.headers(&secret_id, &secret_key);
-        let headers = (&headers).try_into()?;
-
-        let request = client.post(url).headers(headers).body(body).build()?;
-
-        let res: Response = client.execute(request)?.json()?;
-
-        if res.Response.Error.is_some() {
-            let err = dnspod_lib::serde_json::to_string_pretty(&res)?;
-            return Err(anyhow::anyhow!("{}", err));
-        }
-
-        Ok(res)
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use dnspod_mock::{MockDnspod, MockServer};
+
+    use super::*;
+
+    #[test]
+    fn update_record() {
+        let server = MockServer::start(MockDnspod::new().with_credential("id", "key")).unwrap();
+        let id = server
+            .dnspod()
+            .add_record("example.com", "home", "A", "1.1.1.1");
+
+        let args = |subdomain: &str, value: &str| {
+            Args::parse_from([
+                "ddnspod",
+                "--secret-id=id",
+                "--secret-key=key",
+                "--endpoint",
+                server.url(),
+                "-d=example.com",
+                "-s",
+                subdomain,
+                "-v",
+                value,
+            ])
+        };
+        run(args("home", "2.2.2.2")).unwrap();
+
+        let record = server
+            .dnspod()
+            .records("example.com")
+            .into_iter()
+            .find(|r| r.RecordId == id)
+            .unwrap();
+        assert_eq!(record.Value, "2.2.2.2");
+        assert_eq!(record.TTL, 60);
+
+        // 同名的 A 和 AAAA 记录, 按 IP 版本更新
+        let v6 = server
+            .dnspod()
+            .add_record("example.com", "home", "AAAA", "::1");
+        run(args("home", "::2")).unwrap();
+        let records = server.dnspod().records("example.com");
+        let value = |id| &records.iter().find(|r| r.RecordId == id).unwrap().Value;
+        assert_eq!(value(id), "2.2.2.2");
+        assert_eq!(value(v6), "::2");
+
+        let err = run(args("www", "2.2.2.2")).unwrap_err();
+        let err = err.downcast::<dnspod_lib::Error>().unwrap();
+        assert_eq!(
+            err.code().map(|c| c.code()),
+            Some("ResourceNotFound.NoDataOfRecord")
+        );
     }
 }
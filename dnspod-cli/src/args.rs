@@ -1,5 +1,7 @@
 use clap::Parser;
+use dnspod_lib::credential::{ChainProvider, Credential, EnvProvider, ProfileProvider};
 use dnspod_lib::action;
+use dnspod_lib::data_types::RecordStatus;
 
 #[derive(Debug, Parser)]
 #[clap(version, about)]
@@ -7,9 +9,15 @@ pub struct Args {
     #[clap(subcommand)]
     pub action: Action,
     #[arg(long, env = "DNSPOD_SECRET_ID")]
-    pub secret_id: String,
+    pub secret_id: Option<String>,
     #[arg(long, env = "DNSPOD_SECRET_KEY")]
-    pub secret_key: String,
+    pub secret_key: Option<String>,
+    /// 未指定 secret-id/secret-key 时, 使用 ~/.tencentcloud/credentials 中的 profile
+    #[arg(long, env = "TENCENTCLOUD_PROFILE", default_value = "default")]
+    pub profile: String,
+    /// API 地址, 比如国际站 https://dnspod.intl.tencentcloudapi.com
+    #[arg(long, env = "DNSPOD_ENDPOINT")]
+    pub endpoint: Option<String>,
 }
 
 
@@ -27,6 +35,7 @@ macro_rules! impl_enums {
     ) => {
         $(
             $(#[$meta])*
+            #[allow(clippy::large_enum_variant)]
             $vis enum $name {
                 $(
                     $(#[$field_meta])*
@@ -40,9 +49,9 @@ macro_rules! impl_enums {
                         $( $name::$field(v) => v.action(), )*
                     }
                 }
-                #[inline] fn body(&self) -> Vec<u8> {
+                #[inline] fn try_body(&self) -> dnspod_lib::Result<Vec<u8>> {
                     match self {
-                        $( $name::$field(v) => v.body(), )*
+                        $( $name::$field(v) => v.try_body(), )*
                     }
                 }
                 #[inline] fn url(&self) -> &'static str {
@@ -60,6 +69,25 @@ macro_rules! impl_enums {
                         $( $name::$field(v) => v.region(), )*
                     }
                 }
+                #[inline] fn method(&self) -> dnspod_lib::transport::Method {
+                    match self {
+                        $( $name::$field(v) => v.method(), )*
+                    }
+                }
+                #[inline] fn idempotent(&self) -> bool {
+                    match self {
+                        $( $name::$field(v) => v.idempotent(), )*
+                    }
+                }
+                #[inline] fn validate(&self) -> dnspod_lib::Result<()> {
+                    match self {
+                        $( $name::$field(v) => v.validate(), )*
+                    }
+                }
+            }
+
+            impl dnspod_lib::ExtractOutput for $name {
+                type Output = dnspod_lib::serde_json::Value;
             }
         )*
     };
@@ -83,10 +111,17 @@ dnspod_lib::custom_meta_struct! {
     pub enum Record {
         Describe(action::DescribeRecord),
         List(action::DescribeRecordList),
+        Filter(action::DescribeRecordFilterList),
         Create(action::CreateRecord),
         Delete(action::DeleteRecord),
         Modify(action::ModifyRecord),
         ModifyDDNS(action::ModifyDynamicDNS),
+        /// 启用记录
+        Enable(SetRecordStatus<true>),
+        /// 暂停记录
+        Disable(SetRecordStatus<false>),
+        Remark(action::ModifyRecordRemark),
+        ModifyFields(action::ModifyRecordFields),
         DescribeType(action::DescribeRecordType),
         DescribeLineList(action::DescribeRecordLineList),
     }
@@ -100,8 +135,77 @@ dnspod_lib::custom_meta_struct! {
 
 
 
+/// `record enable` 和 `record disable` 的参数, 发送 [`action::ModifyRecordStatus`]
+#[derive(Debug, Clone, clap::Args)]
+pub struct SetRecordStatus<const ENABLE: bool> {
+    /// 域名
+    #[arg(long)]
+    pub domain: String,
+    /// 记录 ID
+    #[arg(long)]
+    pub record_id: u64,
+}
+
+impl<const ENABLE: bool> SetRecordStatus<ENABLE> {
+    fn request(&self) -> action::ModifyRecordStatus {
+        action::ModifyRecordStatus {
+            Domain: self.domain.clone(),
+            RecordId: self.record_id,
+            Status: match ENABLE {
+                true => RecordStatus::ENABLE,
+                false => RecordStatus::DISABLE,
+            },
+            DomainId: None,
+        }
+    }
+}
+
+impl<const ENABLE: bool> dnspod_lib::ExtractCommonParams for SetRecordStatus<ENABLE> {
+    fn action(&self) -> &'static str {
+        self.request().action()
+    }
+    fn try_body(&self) -> dnspod_lib::Result<Vec<u8>> {
+        self.request().try_body()
+    }
+    fn url(&self) -> &'static str {
+        self.request().url()
+    }
+    fn version(&self) -> dnspod_lib::data_types::Version {
+        self.request().version()
+    }
+    fn region(&self) -> Option<dnspod_lib::data_types::Region> {
+        self.request().region()
+    }
+    fn method(&self) -> dnspod_lib::transport::Method {
+        self.request().method()
+    }
+    fn idempotent(&self) -> bool {
+        self.request().idempotent()
+    }
+    fn validate(&self) -> dnspod_lib::Result<()> {
+        self.request().validate()
+    }
+}
+
 impl Args {
     pub fn parse_args() -> Self {
         Self::parse()
     }
 }
+
+/// 依次尝试: 命令行参数, 环境变量, ~/.tencentcloud/credentials
+pub fn credential_provider(
+    secret_id: Option<String>,
+    secret_key: Option<String>,
+    profile: String,
+) -> ChainProvider {
+    let chain = match (secret_id, secret_key) {
+        (Some(secret_id), Some(secret_key)) => {
+            ChainProvider::new().with(Credential::new(secret_id, secret_key))
+        }
+        _ => ChainProvider::new(),
+    };
+    chain
+        .with(EnvProvider::default())
+        .with(ProfileProvider::new(profile))
+}
@@ -7,33 +7,110 @@ use anyhow::Result;
 
 use dnspod_lib::prelude::*;
 use dnspod_lib::serde_json;
-
+use dnspod_lib::transport::ReqwestTransport;
 
 fn main() -> Result<()> {
+    let res = run(Args::parse_args())?;
+    let res = serde_json::to_string_pretty(&res)?;
+    println!("{}", res);
+
+    Ok(())
+}
+
+fn run(args: Args) -> Result<serde_json::Value> {
     let Args {
         action,
         secret_id,
         secret_key,
-    } = Args::parse_args();
+        profile,
+        endpoint,
+    } = args;
 
-    let res = execute(action, &secret_id, &secret_key)?;
-    let res = serde_json::to_string_pretty(&res)?;
-    println!("{}", res);
-
-    Ok(())
+    let provider = args::credential_provider(secret_id, secret_key, profile);
+    let mut client = Client::with_provider(provider, ReqwestTransport::default());
+    if let Some(endpoint) = endpoint {
+        client = client.with_endpoint(endpoint);
+    }
+    Ok(client.execute(action)?)
 }
 
-fn execute(request: impl ExtractCommonParams, secret_id: &str, secret_key: &str) -> Result<serde_json::Value> {
-    let client = reqwest::blocking::Client::new();
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use dnspod_mock::{MockDnspod, MockServer};
+
+    use super::*;
+
+    #[test]
+    fn record_commands() {
+        let server = MockServer::start(MockDnspod::new().with_credential("id", "key")).unwrap();
+        server.dnspod().add_domain("example.com");
+        let run = |command: &[&str]| {
+            let args = [
+                "dnspod-cli",
+                "--secret-id=id",
+                "--secret-key=key",
+                "--endpoint",
+                server.url(),
+            ];
+            run(Args::parse_from(args.iter().chain(command)))
+        };
+
+        let res = run(&[
+            "record",
+            "create",
+            "--domain=example.com",
+            "--sub-domain=www",
+            "--record-type=A",
+            "--value=1.1.1.1",
+        ])
+        .unwrap();
+        let id = res["RecordId"].as_u64().unwrap();
 
-    let url = request.url();
-    let body = request.body();
-    let headers = request.headers(&secret_id, &secret_key);
-    let headers = (&headers).try_into()?;
+        let mx = [
+            "record",
+            "create",
+            "--domain=example.com",
+            "--record-type=MX",
+            "--value=mail.example.com.",
+        ];
+        let err = run(&mx).unwrap_err();
+        let err = err.downcast::<dnspod_lib::Error>().unwrap();
+        assert!(matches!(err, dnspod_lib::Error::InvalidParams(_)));
+        run(&[&mx[..], &["--mx=10", "--ttl=300", "--status=DISABLE"]].concat()).unwrap();
+        let res = run(&["record", "list", "--domain=example.com", "--record-type=MX"]).unwrap();
+        assert_eq!(res["RecordList"][0]["MX"], 10);
+        assert_eq!(res["RecordList"][0]["TTL"], 300);
+        assert_eq!(res["RecordList"][0]["Status"], "DISABLE");
 
-    let request = client.post(url).headers(headers).body(body).build()?;
+        let res = run(&["record", "list", "--domain=example.com", "--subdomain=www"]).unwrap();
+        assert_eq!(res["RecordList"][0]["RecordId"], id);
+        assert_eq!(res["RecordList"][0]["Value"], "1.1.1.1");
 
-    let res: serde_json::Value = client.execute(request)?.json()?;
+        let record_id = format!("--record-id={id}");
+        let www = |command: &str, args: &[&str]| {
+            let mut command = vec!["record", command, "--domain=example.com", &record_id];
+            command.extend(args);
+            run(&command).unwrap();
+            let res = run(&["record", "list", "--domain=example.com", "--subdomain=www"]).unwrap();
+            res["RecordList"][0].clone()
+        };
+        assert_eq!(www("disable", &[])["Status"], "DISABLE");
+        assert_eq!(www("enable", &[])["Status"], "ENABLE");
+        assert_eq!(www("remark", &["--remark=web"])["Remark"], "web");
+        let record = www(
+            "modify-fields",
+            &["--field=ttl=120", "--field=value=2.2.2.2"],
+        );
+        assert_eq!(record["TTL"], 120);
+        assert_eq!(record["Value"], "2.2.2.2");
 
-    Ok(res)
+        run(&["record", "delete", "--domain=example.com", &record_id]).unwrap();
+        let err = run(&["record", "list", "--domain=example.com", "--subdomain=www"]).unwrap_err();
+        let err = err.downcast::<dnspod_lib::Error>().unwrap();
+        assert_eq!(
+            err.code().map(|c| c.code()),
+            Some("ResourceNotFound.NoDataOfRecord")
+        );
+    }
 }
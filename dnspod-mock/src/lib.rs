@@ -0,0 +1,508 @@
+#![doc = include_str!("../README.md")]
+
+mod server;
+mod store;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use dnspod_lib::chrono::FixedOffset;
+use dnspod_lib::clock::{Clock, SystemClock};
+use dnspod_lib::consts::SERVICE;
+use dnspod_lib::credential::Credential;
+use dnspod_lib::response::RecordListItem;
+use dnspod_lib::serde_json::{json, Value};
+use dnspod_lib::signature::Tc3Verifier;
+use dnspod_lib::transport::{AsyncTransport, HttpRequest, HttpResponse, Method, Transport};
+
+pub use server::MockServer;
+pub use store::ApiError;
+use store::{Params, Store};
+
+/// 内存中的 DNSPod API
+///
+/// 校验 TC3-HMAC-SHA256 签名, 处理 [`dnspod_lib::action`] 中的请求, 返回与线上一致的 `Response`.
+/// 可以直接作为 [`Transport`] 使用, 也可以通过 [`MockServer`] 在本地监听 HTTP 请求.
+/// clone 后共享同一份数据
+///
+/// ```
+/// use dnspod_lib::prelude::*;
+/// use dnspod_mock::MockDnspod;
+///
+/// let dnspod = MockDnspod::new().with_credential("id", "key");
+/// dnspod.add_domain("example.com");
+///
+/// let client = Client::with_transport("id", "key", dnspod.clone());
+/// let res = client.execute(CreateRecord {
+///     Domain: "example.com".into(),
+///     SubDomain: "www".into(),
+///     RecordType: dnspod_lib::data_types::RecordType::A,
+///     RecordLine: Default::default(),
+///     Value: "1.1.1.1".into(),
+///     ..Default::default()
+/// }).unwrap();
+/// assert_eq!(dnspod.records("example.com").last().unwrap().RecordId, res.RecordId);
+/// ```
+#[derive(Clone)]
+pub struct MockDnspod {
+    credentials: Arc<Mutex<HashMap<String, Credential>>>,
+    store: Arc<Mutex<Store>>,
+    clock: Arc<dyn Clock>,
+    request_count: Arc<AtomicU64>,
+}
+
+impl Default for MockDnspod {
+    fn default() -> Self {
+        Self {
+            credentials: Default::default(),
+            store: Default::default(),
+            clock: Arc::new(SystemClock),
+            request_count: Default::default(),
+        }
+    }
+}
+
+impl std::fmt::Debug for MockDnspod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockDnspod")
+            .field("domains", &self.domains())
+            .finish_non_exhaustive()
+    }
+}
+
+impl MockDnspod {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// 允许使用该密钥签名的请求
+    pub fn with_credential(
+        self,
+        secret_id: impl Into<String>,
+        secret_key: impl Into<String>,
+    ) -> Self {
+        self.add_credential(Credential::new(secret_id, secret_key));
+        self
+    }
+
+    /// 服务器时间, 用于校验 `X-TC-Timestamp` 和生成 `Date` 头部, 默认为系统时间
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// 允许使用该密钥签名的请求, 带有 token 的临时密钥还会校验 `X-TC-Token`
+    pub fn add_credential(&self, credential: Credential) {
+        self.credentials
+            .lock()
+            .unwrap()
+            .insert(credential.secret_id.clone(), credential);
+    }
+
+    /// 添加域名及其默认的 NS 记录, 返回域名 ID. 域名已存在时返回已有的 ID
+    pub fn add_domain(&self, domain: &str) -> u64 {
+        let now = self.now();
+        let mut store = self.store.lock().unwrap();
+        match store.add_domain(domain, &now) {
+            Ok(id) => id,
+            Err(_) => store.domain_id(domain).expect("invalid domain"),
+        }
+    }
+
+    /// 添加一条默认线路的记录, 不做任何校验, 返回记录 ID. 域名不存在时自动添加
+    pub fn add_record(&self, domain: &str, subdomain: &str, record_type: &str, value: &str) -> u64 {
+        self.add_domain(domain);
+        let now = self.now();
+        let mut store = self.store.lock().unwrap();
+        store
+            .add_record(domain, subdomain, record_type, value, &now)
+            .expect("domain exists")
+    }
+
+    /// 所有域名
+    pub fn domains(&self) -> Vec<String> {
+        self.store.lock().unwrap().domain_names()
+    }
+
+    /// 域名下的所有记录, 域名不存在时为空
+    pub fn records(&self, domain: &str) -> Vec<RecordListItem> {
+        self.store.lock().unwrap().records(domain)
+    }
+
+    /// 处理一个请求, 错误也以 `Response.Error` 的形式返回
+    pub fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        let request_id = self.next_request_id();
+        let response = match self.dispatch(request) {
+            Ok(Value::Object(mut map)) => {
+                map.insert("RequestId".into(), request_id.into());
+                Value::Object(map)
+            }
+            Ok(_) => json!({ "RequestId": request_id }),
+            Err(ApiError { code, message }) => json!({
+                "Error": { "Code": code, "Message": message },
+                "RequestId": request_id,
+            }),
+        };
+        let date = self.clock.now().format("%a, %d %b %Y %H:%M:%S GMT");
+        HttpResponse::ok(json!({ "Response": response }).to_string())
+            .with_header("Content-Type", "application/json")
+            .with_header("Date", date.to_string())
+    }
+
+    fn dispatch(&self, request: &HttpRequest) -> Result<Value, ApiError> {
+        let credentials = self.credentials.lock().unwrap().clone();
+        Tc3Verifier::new(SERVICE, |id| credentials.get(id).cloned())
+            .with_clock(self.clock.clone())
+            .verify(request)
+            .map_err(|e| ApiError::new(e.code(), e.to_string()))?;
+
+        let action = request
+            .headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("X-TC-Action"))
+            .map(|(_, v)| v.as_str())
+            .ok_or_else(|| {
+                ApiError::new("MissingParameter", "The request is missing `X-TC-Action`.")
+            })?;
+        let params = match request.method {
+            Method::GET => Params::from_query(request.url.split_once('?').map_or("", |(_, q)| q)),
+            Method::POST => Params::from_json(&request.body)?,
+        };
+
+        let now = self.now();
+        let mut store = self.store.lock().unwrap();
+        match action {
+            "DescribeDomainList" => store.describe_domain_list(&params),
+            "CreateDomain" => store.create_domain(&params, &now),
+            "DeleteDomain" => store.delete_domain(&params),
+            "CreateRecord" => store.create_record(&params, &now),
+            "DeleteRecord" => store.delete_record(&params),
+            "DescribeRecordList" => store.describe_record_list(&params),
+            "DescribeRecordFilterList" => store.describe_record_filter_list(&params),
+            "DescribeRecord" => store.describe_record(&params),
+            "DescribeRecordType" => store.describe_record_type(&params),
+            "DescribeRecordLineList" => store.describe_record_line_list(&params),
+            "ModifyDynamicDNS" => store.modify_dynamic_dns(&params, &now),
+            "ModifyRecord" => store.modify_record(&params, &now),
+            "ModifyRecordStatus" => store.modify_record_status(&params, &now),
+            "ModifyRecordRemark" => store.modify_record_remark(&params, &now),
+            "ModifyRecordFields" => store.modify_record_fields(&params, &now),
+            _ => Err(ApiError::new(
+                "InvalidAction",
+                format!("The action `{action}` is not supported."),
+            )),
+        }
+    }
+
+    /// 北京时间, 如 2021-03-28 11:27:09
+    fn now(&self) -> String {
+        let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+        self.clock
+            .now()
+            .with_timezone(&beijing)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    }
+
+    fn next_request_id(&self) -> String {
+        let n = self.request_count.fetch_add(1, Ordering::Relaxed);
+        format!("6ef60bec-0242-43af-bb20-{n:012x}")
+    }
+}
+
+impl Transport for MockDnspod {
+    fn send(&self, request: HttpRequest) -> dnspod_lib::Result<HttpResponse> {
+        Ok(self.handle(&request))
+    }
+}
+
+impl AsyncTransport for MockDnspod {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> impl Future<Output = dnspod_lib::Result<HttpResponse>> + Send {
+        std::future::ready(Ok(self.handle(&request)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dnspod_lib::chrono::{Duration, TimeZone, Utc};
+    use dnspod_lib::clock::FixedClock;
+    use dnspod_lib::data_types::RecordType;
+    use dnspod_lib::prelude::*;
+
+    use super::*;
+
+    fn client(dnspod: &MockDnspod) -> Client<&MockDnspod> {
+        Client::with_transport("id", "key", dnspod)
+    }
+
+    #[test]
+    fn ddns_flow() {
+        let dnspod = MockDnspod::new().with_credential("id", "key");
+        let id = dnspod.add_record("example.com", "home", "A", "1.1.1.1");
+        let client = client(&dnspod);
+
+        let res = client
+            .execute(DescribeRecordList {
+                Domain: "example.com".into(),
+                Subdomain: Some("home".into()),
+                Keyword: None,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(res.RecordList.len(), 1);
+        assert_eq!(res.RecordList[0].RecordId, id);
+
+        let res = client
+            .execute(ModifyDynamicDNS {
+                Domain: "example.com".into(),
+                SubDomain: "home".into(),
+                RecordId: id,
+                RecordLine: Default::default(),
+                Value: "2.2.2.2".into(),
+                Ttl: 60,
+            })
+            .unwrap();
+        assert_eq!(res.RecordId, id);
+
+        let record = client
+            .execute(DescribeRecord {
+                Domain: "example.com".into(),
+                RecordId: id,
+            })
+            .unwrap()
+            .RecordInfo;
+        assert_eq!(record.Value, "2.2.2.2");
+        assert_eq!(record.TTL, 60);
+    }
+
+    #[test]
+    fn paginate() {
+        use dnspod_lib::transport::Recorder;
+
+        let dnspod = MockDnspod::new().with_credential("id", "key");
+        for i in 0..250 {
+            dnspod.add_record("example.com", &format!("host{i}"), "A", "1.1.1.1");
+        }
+        dnspod.add_domain("example.org");
+
+        let client = Client::with_transport("id", "key", Recorder::new(&dnspod));
+        let records: Vec<_> = client
+            .records("example.com", Default::default())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        // 两条默认的 NS 记录
+        assert_eq!(records.len(), 252);
+        assert_eq!(records[251].Name, "host249");
+        assert_eq!(client.transport().cassette().interactions.len(), 3);
+
+        let domains: Vec<_> = client
+            .domains(DescribeDomainList {
+                Limit: 1,
+                ..Default::default()
+            })
+            .map(|d| d.unwrap().Name)
+            .collect();
+        assert_eq!(domains, ["example.com", "example.org"]);
+
+        let filter = DescribeRecordList {
+            Subdomain: Some("www".into()),
+            ..Default::default()
+        };
+        assert_eq!(client.records("example.com", filter).count(), 0);
+    }
+
+    #[test]
+    fn filter_records() {
+        use dnspod_lib::data_types::SortType;
+
+        let dnspod = MockDnspod::new().with_credential("id", "key");
+        dnspod.add_record("example.com", "home", "A", "1.1.1.1");
+        dnspod.add_record("example.com", "home", "AAAA", "::1");
+        dnspod.add_record("example.com", "www", "CNAME", "example.org.");
+        let client = client(&dnspod);
+
+        let res = client
+            .execute(DescribeRecordList {
+                Domain: "example.com".into(),
+                Subdomain: Some("home".into()),
+                RecordType: Some(RecordType::AAAA),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(res.RecordList.len(), 1);
+        assert_eq!(res.RecordList[0].Value, "::1");
+
+        let res = client
+            .execute(DescribeRecordFilterList {
+                Domain: "example.com".into(),
+                RecordType: vec![RecordType::A, RecordType::CNAME],
+                SortType: Some(SortType::DESC),
+                ..Default::default()
+            })
+            .unwrap();
+        let names: Vec<_> = res.RecordList.iter().map(|r| r.Name.as_str()).collect();
+        assert_eq!(names, ["www", "home"]);
+    }
+
+    #[test]
+    fn record_params() {
+        use dnspod_lib::data_types::RecordStatus;
+
+        let dnspod = MockDnspod::new().with_credential("id", "key");
+        let domain_id = dnspod.add_domain("example.com");
+        let client = client(&dnspod);
+
+        let id = client
+            .execute(CreateRecord {
+                DomainId: Some(domain_id),
+                Domain: "ignored.com".into(),
+                SubDomain: "@".into(),
+                RecordType: RecordType::MX,
+                RecordLineId: Some("10=1".into()),
+                Value: "mail.example.com.".into(),
+                MX: Some(10),
+                TTL: Some(300),
+                Status: Some(RecordStatus::DISABLE),
+                Remark: Some("mail".into()),
+                ..Default::default()
+            })
+            .unwrap()
+            .RecordId;
+        let record = |id| {
+            client
+                .execute(DescribeRecord {
+                    Domain: "example.com".into(),
+                    RecordId: id,
+                })
+                .unwrap()
+                .RecordInfo
+        };
+        let info = record(id);
+        assert_eq!((info.MX, info.TTL, info.Enabled), (10, 300, 0));
+        assert_eq!(info.RecordLine, "联通");
+        assert_eq!(info.Remark.as_deref(), Some("mail"));
+
+        client
+            .execute(ModifyRecord {
+                Domain: "example.com".into(),
+                SubDomain: "@".into(),
+                RecordId: id,
+                RecordType: RecordType::MX,
+                Value: "mail.example.com.".into(),
+                MX: Some(20),
+                Weight: Some(50),
+                Status: Some(RecordStatus::ENABLE),
+                ..Default::default()
+            })
+            .unwrap();
+        let info = record(id);
+        assert_eq!((info.MX, info.Weight, info.Enabled), (20, Some(50), 1));
+        assert_eq!(info.RecordLine, "默认");
+    }
+
+    #[test]
+    fn api_errors() {
+        let dnspod = MockDnspod::new().with_credential("id", "key");
+        dnspod.add_domain("example.com");
+
+        let err = client(&dnspod)
+            .execute(DescribeRecordList {
+                Domain: "example.com".into(),
+                Subdomain: Some("www".into()),
+                Keyword: None,
+                ..Default::default()
+            })
+            .unwrap_err();
+        assert_eq!(
+            err.code().map(|c| c.code()),
+            Some("ResourceNotFound.NoDataOfRecord")
+        );
+        assert!(err.request_id().is_some());
+
+        let err = client(&dnspod)
+            .execute(DeleteRecord {
+                Domain: "example.org".into(),
+                RecordId: 1,
+            })
+            .unwrap_err();
+        assert_eq!(
+            err.code().map(|c| c.code()),
+            Some("InvalidParameterValue.DomainNotExists")
+        );
+
+        let err = client(&dnspod)
+            .execute(CreateRecord {
+                Domain: "example.com".into(),
+                SubDomain: "www".into(),
+                RecordType: RecordType::AAAA,
+                RecordLine: Default::default(),
+                Value: "1.1.1.1".into(),
+                ..Default::default()
+            })
+            .unwrap_err();
+        assert_eq!(
+            err.code().map(|c| c.code()),
+            Some("InvalidParameter.RecordValueInvalid")
+        );
+    }
+
+    #[test]
+    fn signature() {
+        let dnspod = MockDnspod::new().with_credential("id", "key");
+        dnspod.add_domain("example.com");
+        let request = DescribeDomainList {
+            Type: Default::default(),
+            Offset: 0,
+            Limit: 20,
+            GroupId: 0,
+            Keyword: None,
+        };
+
+        let err = Client::with_transport("id", "wrong", &dnspod)
+            .execute(request.clone())
+            .unwrap_err();
+        assert_eq!(
+            err.code().map(|c| c.code()),
+            Some("AuthFailure.SignatureFailure")
+        );
+
+        let err = Client::with_transport("other", "key", &dnspod)
+            .execute(request.clone())
+            .unwrap_err();
+        assert_eq!(
+            err.code().map(|c| c.code()),
+            Some("AuthFailure.SecretIdNotFound")
+        );
+
+        let res = client(&dnspod)
+            .with_method(Method::GET)
+            .execute(request)
+            .unwrap();
+        assert_eq!(res.DomainList[0].Name, "example.com");
+    }
+
+    #[test]
+    fn clock_skew() {
+        let now = Utc.with_ymd_and_hms(2023, 9, 1, 12, 0, 0).unwrap();
+        let dnspod = MockDnspod::new()
+            .with_credential("id", "key")
+            .with_clock(FixedClock(now));
+        dnspod.add_domain("example.com");
+
+        // 客户端时间慢了一小时, 根据 Date 头部校正后重试
+        let client = client(&dnspod).with_clock(FixedClock(now - Duration::hours(1)));
+        let res = client
+            .execute(DescribeRecordLineList {
+                Domain: "example.com".into(),
+                DomainGrade: Default::default(),
+            })
+            .unwrap();
+        assert_eq!(res.LineList[0].Name, "默认");
+        assert_eq!(client.clock_skew(), Duration::hours(1));
+    }
+}
@@ -0,0 +1,39 @@
+use clap::Parser;
+use dnspod_mock::{MockDnspod, MockServer};
+
+/// 本地的 DNSPod API 服务器, 数据只保存在内存中
+#[derive(Debug, Parser)]
+#[clap(version, about)]
+struct Args {
+    /// 监听地址
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    addr: String,
+    /// 允许的 SecretId
+    #[arg(long, env = "DNSPOD_SECRET_ID")]
+    secret_id: String,
+    /// 允许的 SecretKey
+    #[arg(long, env = "DNSPOD_SECRET_KEY")]
+    secret_key: String,
+    /// 预先添加的域名, 可以指定多次
+    #[arg(short, long)]
+    domain: Vec<String>,
+}
+
+fn main() -> std::io::Result<()> {
+    let Args {
+        addr,
+        secret_id,
+        secret_key,
+        domain,
+    } = Args::parse();
+
+    let dnspod = MockDnspod::new().with_credential(secret_id, secret_key);
+    for domain in domain {
+        dnspod.add_domain(&domain);
+    }
+
+    let server = MockServer::bind(addr, dnspod)?;
+    println!("DNSPOD_ENDPOINT={}", server.url());
+    server.join();
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+use dnspod_lib::transport::{HttpRequest, Method};
+use tiny_http::{Header, Request, Response, Server};
+
+use crate::MockDnspod;
+
+/// 在本地监听 HTTP 请求的 [`MockDnspod`], drop 时停止
+///
+/// 配合 `Client::with_endpoint` 或 `DNSPOD_ENDPOINT` 环境变量, 可以端到端地测试 ddnspod 和 dnspod-cli
+///
+/// ```
+/// use dnspod_mock::{MockDnspod, MockServer};
+///
+/// let server = MockServer::start(MockDnspod::new().with_credential("id", "key")).unwrap();
+/// println!("DNSPOD_ENDPOINT={}", server.url());
+/// ```
+pub struct MockServer {
+    url: String,
+    dnspod: MockDnspod,
+    server: Arc<Server>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for MockServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockServer")
+            .field("url", &self.url)
+            .field("dnspod", &self.dnspod)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MockServer {
+    /// 监听 `127.0.0.1` 上的随机端口
+    pub fn start(dnspod: MockDnspod) -> io::Result<Self> {
+        Self::bind("127.0.0.1:0", dnspod)
+    }
+
+    /// 监听指定的地址
+    pub fn bind(addr: impl ToSocketAddrs, dnspod: MockDnspod) -> io::Result<Self> {
+        let server = Arc::new(Server::http(addr).map_err(io::Error::other)?);
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .ok_or_else(|| io::Error::other("not an ip address"))?;
+        let url = format!("http://{addr}");
+
+        let thread = {
+            let server = server.clone();
+            let dnspod = dnspod.clone();
+            std::thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle(&dnspod, addr, request);
+                }
+            })
+        };
+
+        Ok(Self {
+            url,
+            dnspod,
+            server,
+            thread: Some(thread),
+        })
+    }
+
+    /// 服务器地址, 如 `http://127.0.0.1:8080`
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn dnspod(&self) -> &MockDnspod {
+        &self.dnspod
+    }
+
+    /// 阻塞当前线程, 直到服务器停止
+    pub fn join(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn handle(dnspod: &MockDnspod, addr: SocketAddr, mut request: Request) {
+    let method = match request.method() {
+        tiny_http::Method::Get => Method::GET,
+        tiny_http::Method::Post => Method::POST,
+        _ => {
+            let _ = request.respond(Response::empty(405));
+            return;
+        }
+    };
+
+    let mut host = addr.to_string();
+    let mut headers = std::collections::HashMap::new();
+    for header in request.headers() {
+        let name = header.field.as_str().to_string();
+        if name.eq_ignore_ascii_case("host") {
+            host = header.value.to_string();
+        }
+        headers.insert(name, header.value.to_string());
+    }
+    let mut body = Vec::new();
+    if let Err(e) = request.as_reader().read_to_end(&mut body) {
+        let _ = request.respond(Response::from_string(e.to_string()).with_status_code(400));
+        return;
+    }
+
+    let response = dnspod.handle(&HttpRequest {
+        method,
+        url: format!("http://{host}{}", request.url()),
+        headers,
+        body,
+    });
+
+    let mut res = Response::from_data(response.body).with_status_code(response.status);
+    for (name, value) in &response.headers {
+        if let Ok(header) = Header::from_bytes(name.as_bytes(), value.as_bytes()) {
+            res.add_header(header);
+        }
+    }
+    let _ = request.respond(res);
+}
+
+#[cfg(test)]
+mod tests {
+    use dnspod_lib::prelude::*;
+    use dnspod_lib::transport::ReqwestTransport;
+
+    use super::*;
+
+    #[test]
+    fn serve_http() {
+        let server = MockServer::start(MockDnspod::new().with_credential("id", "key")).unwrap();
+        server.dnspod().add_domain("example.com");
+        let client = Client::with_transport("id", "key", ReqwestTransport::default())
+            .with_endpoint(server.url());
+
+        let res = client
+            .execute(DescribeDomainList {
+                Type: Default::default(),
+                Offset: 0,
+                Limit: 20,
+                GroupId: 0,
+                Keyword: None,
+            })
+            .unwrap();
+        assert_eq!(res.DomainList[0].Name, "example.com");
+        assert_eq!(res.DomainList[0].RecordCount, 2);
+
+        let res = client
+            .with_method(Method::GET)
+            .execute(DescribeRecordList {
+                Domain: "example.com".into(),
+                Subdomain: Some("@".into()),
+                Keyword: None,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(res.RecordList.len(), 2);
+        assert_eq!(res.RecordList[0].Type, "NS");
+    }
+}
@@ -0,0 +1,1172 @@
+//! 内存中的域名和解析记录, 以及各个 Action 的处理逻辑
+
+use std::collections::HashSet;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use dnspod_lib::response::{DomainListItem, RecordInfo, RecordListItem};
+use dnspod_lib::serde_json::{self, json, Map, Value};
+
+/// 新添加的域名使用的 DNS 服务器
+const NAME_SERVERS: [&str; 2] = ["f1g1ns1.dnspod.net", "f1g1ns2.dnspod.net"];
+
+/// 支持的记录类型
+const RECORD_TYPES: [&str; 13] = [
+    "A",
+    "CNAME",
+    "MX",
+    "TXT",
+    "AAAA",
+    "NS",
+    "CAA",
+    "SRV",
+    "HTTPS",
+    "SVCB",
+    "SPF",
+    "显性URL",
+    "隐性URL",
+];
+
+/// 支持的线路: (名称, 线路 ID)
+const RECORD_LINES: [(&str, &str); 5] = [
+    ("默认", "0"),
+    ("电信", "10=0"),
+    ("联通", "10=1"),
+    ("移动", "10=3"),
+    ("境外", "3=0"),
+];
+
+const DOMAIN_GRADES: [&str; 10] = [
+    "D_FREE",
+    "D_PLUS",
+    "D_EXTRA",
+    "D_EXPERT",
+    "D_ULTRA",
+    "DP_FREE",
+    "DP_PLUS",
+    "DP_EXTRA",
+    "DP_EXPERT",
+    "DP_ULTRA",
+];
+
+/// 返回给客户端的错误, 对应 `Response.Error`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    pub code: String,
+    pub message: String,
+}
+
+impl ApiError {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    fn missing(name: &str) -> Self {
+        Self::new(
+            "MissingParameter",
+            format!("The request is missing a required parameter `{name}`."),
+        )
+    }
+
+    fn invalid_value(name: &str) -> Self {
+        Self::new(
+            "InvalidParameterValue",
+            format!("The value of parameter `{name}` is invalid."),
+        )
+    }
+
+    fn domain_not_exists() -> Self {
+        Self::new(
+            "InvalidParameterValue.DomainNotExists",
+            "The current domain is incorrect. Please return and try again.",
+        )
+    }
+
+    fn record_id_invalid() -> Self {
+        Self::new(
+            "InvalidParameter.RecordIdInvalid",
+            "The record ID is invalid.",
+        )
+    }
+}
+
+type ApiResult = Result<Value, ApiError>;
+
+/// 请求参数, POST 请求来自 json 内容, GET 请求来自 query
+#[derive(Debug, Default)]
+pub struct Params(Map<String, Value>);
+
+impl Params {
+    pub fn from_json(body: &[u8]) -> Result<Self, ApiError> {
+        if body.is_empty() {
+            return Ok(Self::default());
+        }
+        match serde_json::from_slice(body) {
+            Ok(Value::Object(map)) => Ok(Self(map)),
+            _ => Err(ApiError::new(
+                "InvalidParameter",
+                "The request body is not a valid JSON object.",
+            )),
+        }
+    }
+
+    pub fn from_query(query: &str) -> Self {
+        let map = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+                (percent_decode(k), Value::String(percent_decode(v)))
+            })
+            .collect();
+        Self(map)
+    }
+
+    /// 字符串参数, 数字也按字符串返回
+    fn str(&self, name: &str) -> Option<String> {
+        match self.0.get(name)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+
+    fn required(&self, name: &str) -> Result<String, ApiError> {
+        self.str(name).ok_or_else(|| ApiError::missing(name))
+    }
+
+    /// 整数参数, GET 请求中的整数是字符串
+    fn u64(&self, name: &str) -> Result<Option<u64>, ApiError> {
+        match self.0.get(name) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::Number(n)) => n.as_u64().map(Some).ok_or(ApiError::invalid_value(name)),
+            Some(Value::String(s)) => s
+                .parse()
+                .map(Some)
+                .map_err(|_| ApiError::invalid_value(name)),
+            Some(_) => Err(ApiError::invalid_value(name)),
+        }
+    }
+
+    fn required_u64(&self, name: &str) -> Result<u64, ApiError> {
+        self.u64(name)?.ok_or_else(|| ApiError::missing(name))
+    }
+
+    /// 布尔参数, GET 请求中为 `true`/`false`
+    fn bool(&self, name: &str) -> Result<Option<bool>, ApiError> {
+        match self.0.get(name) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::Bool(b)) => Ok(Some(*b)),
+            Some(Value::String(s)) => s
+                .parse()
+                .map(Some)
+                .map_err(|_| ApiError::invalid_value(name)),
+            Some(_) => Err(ApiError::invalid_value(name)),
+        }
+    }
+
+    /// 数组参数, GET 请求中为 `name.0`、`name.1` ...
+    fn list(&self, name: &str) -> Vec<String> {
+        let str = |v: &Value| match v {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        };
+        if let Some(Value::Array(list)) = self.0.get(name) {
+            return list.iter().filter_map(str).collect();
+        }
+        (0..)
+            .map_while(|i| self.0.get(&format!("{name}.{i}")))
+            .filter_map(str)
+            .collect()
+    }
+
+    /// `{Key, Value}` 数组参数, GET 请求中为 `name.0.Key`、`name.0.Value` ...
+    fn key_values(&self, name: &str) -> Vec<(String, String)> {
+        let str = |v: Option<&Value>| match v {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Number(n)) => n.to_string(),
+            _ => String::new(),
+        };
+        if let Some(Value::Array(list)) = self.0.get(name) {
+            return list
+                .iter()
+                .map(|kv| (str(kv.get("Key")), str(kv.get("Value"))))
+                .collect();
+        }
+        (0..)
+            .map_while(|i| {
+                let key = self.0.get(&format!("{name}.{i}.Key"))?;
+                Some((
+                    str(Some(key)),
+                    str(self.0.get(&format!("{name}.{i}.Value"))),
+                ))
+            })
+            .collect()
+    }
+}
+
+fn percent_decode(s: &str) -> String {
+    let hex = |b: u8| (b as char).to_digit(16);
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                (Some(h), Some(l)) => {
+                    out.push((h * 16 + l) as u8);
+                    i += 2;
+                }
+                _ => out.push(b'%'),
+            },
+            b'+' => out.push(b' '),
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+struct Domain {
+    id: u64,
+    name: String,
+    created_on: String,
+    updated_on: String,
+    records: Vec<RecordListItem>,
+}
+
+/// 所有的域名和解析记录
+pub struct Store {
+    next_domain_id: u64,
+    next_record_id: u64,
+    domains: Vec<Domain>,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self {
+            next_domain_id: 1,
+            next_record_id: 1,
+            domains: Vec::new(),
+        }
+    }
+}
+
+impl Store {
+    fn domain(&self, name: &str) -> Result<&Domain, ApiError> {
+        self.domains
+            .iter()
+            .find(|d| d.name == name)
+            .ok_or_else(ApiError::domain_not_exists)
+    }
+
+    fn domain_mut(&mut self, name: &str) -> Result<&mut Domain, ApiError> {
+        self.domains
+            .iter_mut()
+            .find(|d| d.name == name)
+            .ok_or_else(ApiError::domain_not_exists)
+    }
+
+    /// 添加域名, 同时添加默认的 NS 记录. 域名已存在时返回 `FailedOperation.DomainExists`
+    pub fn add_domain(&mut self, name: &str, now: &str) -> Result<u64, ApiError> {
+        if !name.contains('.') || name.starts_with('.') || name.ends_with('.') {
+            return Err(ApiError::new(
+                "InvalidParameter.DomainInvalid",
+                "The domain is invalid.",
+            ));
+        }
+        if self.domain(name).is_ok() {
+            return Err(ApiError::new(
+                "FailedOperation.DomainExists",
+                "The domain already exists.",
+            ));
+        }
+        let id = self.next_domain_id;
+        self.next_domain_id += 1;
+        let records = NAME_SERVERS
+            .iter()
+            .map(|ns| {
+                let mut record = new_record(self.next_record_id, "@", "NS", &format!("{ns}."), now);
+                record.TTL = 86400;
+                record.DefaultNS = Some(true);
+                self.next_record_id += 1;
+                record
+            })
+            .collect();
+        self.domains.push(Domain {
+            id,
+            name: name.to_string(),
+            created_on: now.to_string(),
+            updated_on: now.to_string(),
+            records,
+        });
+        Ok(id)
+    }
+
+    /// 添加记录, 不做任何校验
+    pub fn add_record(
+        &mut self,
+        domain: &str,
+        subdomain: &str,
+        record_type: &str,
+        value: &str,
+        now: &str,
+    ) -> Result<u64, ApiError> {
+        let id = self.next_record_id;
+        let domain = self.domain_mut(domain)?;
+        domain
+            .records
+            .push(new_record(id, subdomain, record_type, value, now));
+        domain.updated_on = now.to_string();
+        self.next_record_id += 1;
+        Ok(id)
+    }
+
+    pub fn domain_id(&self, name: &str) -> Option<u64> {
+        self.domain(name).map(|d| d.id).ok()
+    }
+
+    pub fn domain_names(&self) -> Vec<String> {
+        self.domains.iter().map(|d| d.name.clone()).collect()
+    }
+
+    pub fn records(&self, domain: &str) -> Vec<RecordListItem> {
+        self.domain(domain)
+            .map(|d| d.records.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn describe_domain_list(&self, params: &Params) -> ApiResult {
+        let keyword = params.str("Keyword").unwrap_or_default();
+        let offset = params.u64("Offset")?.unwrap_or(0) as usize;
+        let limit = limit(params, 3000)?;
+
+        let matched: Vec<_> = self
+            .domains
+            .iter()
+            .filter(|d| d.name.contains(&keyword))
+            .collect();
+        let list: Vec<_> = matched
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .map(|d| domain_list_item(d))
+            .collect();
+        if list.is_empty() {
+            return Err(ApiError::new(
+                "ResourceNotFound.NoDataOfDomain",
+                "The domain list is empty.",
+            ));
+        }
+
+        let total = self.domains.len() as u64;
+        Ok(json!({
+            "DomainCountInfo": {
+                "DomainTotal": matched.len(),
+                "AllTotal": total,
+                "MineTotal": total,
+                "ShareTotal": 0,
+                "VipTotal": 0,
+                "PauseTotal": 0,
+                "ErrorTotal": 0,
+                "LockTotal": 0,
+                "SpamTotal": 0,
+                "VipExpire": 0,
+                "ShareOutTotal": 0,
+                "GroupTotal": total,
+            },
+            "DomainList": list,
+        }))
+    }
+
+    pub fn create_domain(&mut self, params: &Params, now: &str) -> ApiResult {
+        let name = params.required("Domain")?;
+        let id = self.add_domain(&name, now)?;
+        Ok(json!({
+            "DomainInfo": {
+                "Id": id,
+                "Domain": name,
+                "Punycode": name,
+                "GradeNsList": NAME_SERVERS,
+            }
+        }))
+    }
+
+    pub fn delete_domain(&mut self, params: &Params) -> ApiResult {
+        let name = params.required("Domain")?;
+        self.domain(&name)?;
+        self.domains.retain(|d| d.name != name);
+        Ok(json!({}))
+    }
+
+    pub fn create_record(&mut self, params: &Params, now: &str) -> ApiResult {
+        let domain = self.domain_by_id_or_name(params)?.name.clone();
+
+        let mut record = new_record(self.next_record_id, "@", "A", "", now);
+        apply_record_params(&mut record, params, true)?;
+        let domain = self.domain_mut(&domain)?;
+        if domain.records.iter().any(|r| {
+            r.Name == record.Name
+                && r.Type == record.Type
+                && r.Line == record.Line
+                && r.Value == record.Value
+        }) {
+            return Err(ApiError::new(
+                "InvalidParameter.DomainRecordExist",
+                "The record already exists.",
+            ));
+        }
+
+        let id = record.RecordId;
+        domain.records.push(record);
+        domain.updated_on = now.to_string();
+        self.next_record_id += 1;
+        Ok(json!({ "RecordId": id }))
+    }
+
+    pub fn delete_record(&mut self, params: &Params) -> ApiResult {
+        let domain = self.domain_mut(&params.required("Domain")?)?;
+        let id = params.required_u64("RecordId")?;
+        let index = domain
+            .records
+            .iter()
+            .position(|r| r.RecordId == id)
+            .ok_or_else(ApiError::record_id_invalid)?;
+        domain.records.remove(index);
+        Ok(json!({}))
+    }
+
+    pub fn modify_record(&mut self, params: &Params, now: &str) -> ApiResult {
+        let record = self.record_mut(params)?;
+        let mut modified = record.clone();
+        apply_record_params(&mut modified, params, true)?;
+        modified.UpdatedOn = now.to_string();
+        *record = modified;
+        Ok(json!({ "RecordId": record.RecordId }))
+    }
+
+    pub fn modify_dynamic_dns(&mut self, params: &Params, now: &str) -> ApiResult {
+        let record = self.record_mut(params)?;
+        let mut modified = record.clone();
+        apply_record_params(&mut modified, params, false)?;
+        if let Some(ttl) = params.u64("Ttl")? {
+            modified.TTL = ttl;
+        }
+        modified.UpdatedOn = now.to_string();
+        *record = modified;
+        Ok(json!({ "RecordId": record.RecordId }))
+    }
+
+    pub fn modify_record_status(&mut self, params: &Params, now: &str) -> ApiResult {
+        let status = params.required("Status")?;
+        if status != "ENABLE" && status != "DISABLE" {
+            return Err(ApiError::invalid_value("Status"));
+        }
+        let record = self.record_mut(params)?;
+        record.Status = status;
+        record.UpdatedOn = now.to_string();
+        Ok(json!({ "RecordId": record.RecordId }))
+    }
+
+    pub fn modify_record_remark(&mut self, params: &Params, now: &str) -> ApiResult {
+        let remark = params.str("Remark").unwrap_or_default();
+        let record = self.record_mut(params)?;
+        record.Remark = remark;
+        record.UpdatedOn = now.to_string();
+        Ok(json!({}))
+    }
+
+    /// 把 FieldList 合并到记录现有的参数上, 再按 ModifyRecord 检查
+    pub fn modify_record_fields(&mut self, params: &Params, now: &str) -> ApiResult {
+        let fields = params.key_values("FieldList");
+        if fields.is_empty() {
+            return Err(ApiError::missing("FieldList"));
+        }
+        let record = self.record_mut(params)?;
+        let mut merged = json!({
+            "SubDomain": record.Name,
+            "RecordType": record.Type,
+            "RecordLine": record.Line,
+            "Value": record.Value,
+            "TTL": record.TTL,
+            "MX": record.MX,
+            "Weight": record.Weight,
+            "Status": record.Status,
+        });
+        for (key, value) in fields {
+            let name = match key.as_str() {
+                "sub_domain" => "SubDomain",
+                "record_type" => "RecordType",
+                "record_line" => "RecordLine",
+                "record_line_id" => "RecordLineId",
+                "value" => "Value",
+                "ttl" => "TTL",
+                "mx" => "MX",
+                "weight" => "Weight",
+                "status" => "Status",
+                _ => return Err(ApiError::invalid_value("FieldList")),
+            };
+            merged[name] = value.into();
+        }
+        let Value::Object(merged) = merged else {
+            unreachable!()
+        };
+
+        let mut modified = record.clone();
+        apply_record_params(&mut modified, &Params(merged), true)?;
+        modified.UpdatedOn = now.to_string();
+        *record = modified;
+        Ok(json!({ "RecordId": record.RecordId }))
+    }
+
+    fn record_mut(&mut self, params: &Params) -> Result<&mut RecordListItem, ApiError> {
+        let domain = self.domain_by_id_or_name(params)?.name.clone();
+        let domain = self.domain_mut(&domain)?;
+        let id = params.required_u64("RecordId")?;
+        domain
+            .records
+            .iter_mut()
+            .find(|r| r.RecordId == id)
+            .ok_or_else(ApiError::record_id_invalid)
+    }
+
+    pub fn describe_record(&self, params: &Params) -> ApiResult {
+        let domain = self.domain(&params.required("Domain")?)?;
+        let id = params.required_u64("RecordId")?;
+        let r = domain
+            .records
+            .iter()
+            .find(|r| r.RecordId == id)
+            .ok_or_else(ApiError::record_id_invalid)?;
+        let info = RecordInfo {
+            Id: r.RecordId,
+            SubDomain: r.Name.clone(),
+            RecordType: r.Type.clone(),
+            RecordLine: r.Line.clone(),
+            RecordLineId: r.LineId.clone(),
+            Value: r.Value.clone(),
+            Weight: r.Weight,
+            MX: r.MX.unwrap_or(0),
+            TTL: r.TTL,
+            Enabled: (r.Status == "ENABLE") as u64,
+            MonitorStatus: r.MonitorStatus.clone(),
+            Remark: Some(r.Remark.clone()),
+            UpdatedOn: r.UpdatedOn.clone(),
+            DomainId: domain.id,
+        };
+        Ok(json!({ "RecordInfo": info }))
+    }
+
+    /// `DomainId` 优先于 `Domain`
+    fn domain_by_id_or_name(&self, params: &Params) -> Result<&Domain, ApiError> {
+        match params.u64("DomainId")? {
+            Some(id) => self
+                .domains
+                .iter()
+                .find(|d| d.id == id)
+                .ok_or_else(ApiError::domain_not_exists),
+            None => self.domain(&params.required("Domain")?),
+        }
+    }
+
+    /// 不支持分组, 忽略 `GroupId`
+    pub fn describe_record_list(&self, params: &Params) -> ApiResult {
+        let domain = self.domain_by_id_or_name(params)?;
+        let subdomain = params.str("Subdomain").unwrap_or_default();
+        let keyword = params.str("Keyword").unwrap_or_default();
+        let record_type = params.str("RecordType").unwrap_or_default();
+        let record_line = params.str("RecordLine").unwrap_or_default();
+        let record_line_id = params.str("RecordLineId").unwrap_or_default();
+
+        let matched: Vec<_> = domain
+            .records
+            .iter()
+            .filter(|r| subdomain.is_empty() || r.Name == subdomain)
+            .filter(|r| {
+                keyword.is_empty() || r.Name.contains(&keyword) || r.Value.contains(&keyword)
+            })
+            .filter(|r| record_type.is_empty() || r.Type == record_type)
+            .filter(|r| match record_line_id.is_empty() {
+                true => record_line.is_empty() || r.Line == record_line,
+                false => r.LineId == record_line_id,
+            })
+            .collect();
+        record_list(matched, params)
+    }
+
+    /// 不支持分组, 忽略 `GroupId` 和 `ProjectId`
+    pub fn describe_record_filter_list(&self, params: &Params) -> ApiResult {
+        let domain = self.domain_by_id_or_name(params)?;
+        let subdomain = params.str("SubDomain").unwrap_or_default();
+        let exact = params.bool("IsExactSubDomain")?.unwrap_or(false);
+        let keyword = params.str("Keyword").unwrap_or_default();
+        let value = params.str("RecordValue").unwrap_or_default();
+        let remark = params.str("Remark").unwrap_or_default();
+        let types = params.list("RecordType");
+        let lines = params.list("RecordLine");
+        let statuses = params.list("RecordStatus");
+        let range = |begin: &str, end: &str| -> Result<_, ApiError> {
+            let begin = params.u64(begin)?;
+            let end = params.u64(end)?;
+            Ok(move |v: Option<u64>| match (begin, end) {
+                (None, None) => true,
+                _ => v.is_some_and(|v| begin.is_none_or(|b| v >= b) && end.is_none_or(|e| v <= e)),
+            })
+        };
+        let weight = range("WeightBegin", "WeightEnd")?;
+        let mx = range("MXBegin", "MXEnd")?;
+        let ttl = range("TTLBegin", "TTLEnd")?;
+        let updated_begin = params.str("UpdatedAtBegin").unwrap_or_default();
+        let updated_end = params.str("UpdatedAtEnd").unwrap_or_default();
+
+        let matched: Vec<_> = domain
+            .records
+            .iter()
+            .filter(|r| match exact {
+                true => subdomain.is_empty() || r.Name == subdomain,
+                false => r.Name.contains(&subdomain),
+            })
+            .filter(|r| {
+                keyword.is_empty() || r.Name.contains(&keyword) || r.Value.contains(&keyword)
+            })
+            .filter(|r| r.Value.contains(&value) && r.Remark.contains(&remark))
+            .filter(|r| types.is_empty() || types.contains(&r.Type))
+            .filter(|r| lines.is_empty() || lines.contains(&r.Line))
+            .filter(|r| statuses.is_empty() || statuses.contains(&r.Status))
+            .filter(|r| weight(r.Weight) && mx(r.MX) && ttl(Some(r.TTL)))
+            .filter(|r| {
+                // UpdatedOn 为 `2021-03-28 11:27:09`, 按日期比较
+                let date = r.UpdatedOn.get(..10).unwrap_or_default();
+                (updated_begin.is_empty() || date >= updated_begin.as_str())
+                    && (updated_end.is_empty() || date <= updated_end.as_str())
+            })
+            .collect();
+        record_list(matched, params)
+    }
+
+    pub fn describe_record_type(&self, params: &Params) -> ApiResult {
+        domain_grade(params)?;
+        Ok(json!({ "TypeList": RECORD_TYPES }))
+    }
+
+    pub fn describe_record_line_list(&self, params: &Params) -> ApiResult {
+        self.domain(&params.required("Domain")?)?;
+        domain_grade(params)?;
+        let lines: Vec<_> = RECORD_LINES
+            .iter()
+            .map(|(name, id)| json!({ "Name": name, "LineId": id }))
+            .collect();
+        Ok(json!({ "LineList": lines, "LineGroupList": [] }))
+    }
+}
+
+/// 按 `SortField`/`SortType` 排序后, 返回 `Offset`/`Limit` 指定的一页
+fn record_list(mut matched: Vec<&RecordListItem>, params: &Params) -> ApiResult {
+    if let Some(field) = params.str("SortField") {
+        let key = |r: &RecordListItem| -> Value {
+            match field.as_str() {
+                "name" => r.Name.clone().into(),
+                "line" => r.Line.clone().into(),
+                "type" => r.Type.clone().into(),
+                "value" => r.Value.clone().into(),
+                "weight" => r.Weight.unwrap_or_default().into(),
+                "mx" => r.MX.unwrap_or_default().into(),
+                "ttl" => r.TTL.into(),
+                _ => r.UpdatedOn.clone().into(),
+            }
+        };
+        if ![
+            "name",
+            "line",
+            "type",
+            "value",
+            "weight",
+            "mx",
+            "ttl",
+            "updated_on",
+        ]
+        .contains(&field.as_str())
+        {
+            return Err(ApiError::invalid_value("SortField"));
+        }
+        matched.sort_by(|a, b| match (key(a), key(b)) {
+            (Value::Number(a), Value::Number(b)) => a.as_u64().cmp(&b.as_u64()),
+            (a, b) => a.as_str().cmp(&b.as_str()),
+        });
+    }
+    match params.str("SortType").as_deref() {
+        None | Some("ASC") => {}
+        Some("DESC") => matched.reverse(),
+        Some(_) => return Err(ApiError::invalid_value("SortType")),
+    }
+
+    let offset = params.u64("Offset")?.unwrap_or(0) as usize;
+    let limit = limit(params, 100)?;
+    let list: Vec<_> = matched.iter().skip(offset).take(limit).collect();
+    if list.is_empty() {
+        return Err(ApiError::new(
+            "ResourceNotFound.NoDataOfRecord",
+            "The record list is empty.",
+        ));
+    }
+
+    let subdomains: HashSet<_> = matched.iter().map(|r| &r.Name).collect();
+    Ok(json!({
+        "RecordCountInfo": {
+            "SubdomainCount": subdomains.len(),
+            "TotalCount": matched.len(),
+            "ListCount": list.len(),
+        },
+        "RecordList": list,
+    }))
+}
+
+fn limit(params: &Params, default: u64) -> Result<usize, ApiError> {
+    match params.u64("Limit")?.unwrap_or(default) {
+        limit @ 1..=3000 => Ok(limit as usize),
+        _ => Err(ApiError::new(
+            "InvalidParameterValue.LimitInvalid",
+            "The `Limit` is invalid.",
+        )),
+    }
+}
+
+fn domain_grade(params: &Params) -> Result<String, ApiError> {
+    let grade = params.required("DomainGrade")?;
+    if !DOMAIN_GRADES.contains(&grade.as_str()) {
+        return Err(ApiError::new(
+            "InvalidParameterValue.DomainGradeInvalid",
+            "The domain grade is invalid.",
+        ));
+    }
+    Ok(grade)
+}
+
+fn new_record(id: u64, name: &str, record_type: &str, value: &str, now: &str) -> RecordListItem {
+    RecordListItem {
+        RecordId: id,
+        Value: value.to_string(),
+        Status: "ENABLE".into(),
+        UpdatedOn: now.to_string(),
+        Name: name.to_string(),
+        Line: RECORD_LINES[0].0.into(),
+        LineId: RECORD_LINES[0].1.into(),
+        Type: record_type.to_string(),
+        Weight: None,
+        MonitorStatus: "".into(),
+        Remark: "".into(),
+        TTL: 600,
+        MX: None,
+        DefaultNS: None,
+    }
+}
+
+/// 按请求参数修改记录并校验, `with_type` 为 false 时记录类型不变 (ModifyDynamicDNS)
+fn apply_record_params(
+    record: &mut RecordListItem,
+    params: &Params,
+    with_type: bool,
+) -> Result<(), ApiError> {
+    match params.str("SubDomain") {
+        Some(name) if !name.is_empty() => record.Name = name,
+        _ => record.Name = "@".into(),
+    }
+    if with_type {
+        record.Type = params.required("RecordType")?;
+        if !RECORD_TYPES.contains(&record.Type.as_str()) {
+            return Err(ApiError::new(
+                "InvalidParameter.RecordTypeInvalid",
+                "The record type is invalid.",
+            ));
+        }
+    }
+
+    let line = params.required("RecordLine")?;
+    let line_id = params.str("RecordLineId");
+    // RecordLineId 优先于 RecordLine
+    let (line, id) = RECORD_LINES
+        .iter()
+        .find(|(name, id)| match &line_id {
+            Some(line_id) => id == line_id,
+            None => *name == line,
+        })
+        .ok_or(ApiError::new(
+            "InvalidParameter.RecordLineInvalid",
+            "The record line is invalid.",
+        ))?;
+    record.Line = line.to_string();
+    record.LineId = id.to_string();
+
+    record.Value = params.required("Value")?;
+    let valid = match record.Type.as_str() {
+        "A" => record.Value.parse::<Ipv4Addr>().is_ok(),
+        "AAAA" => record.Value.parse::<Ipv6Addr>().is_ok(),
+        _ => !record.Value.is_empty(),
+    };
+    if !valid {
+        return Err(ApiError::new(
+            "InvalidParameter.RecordValueInvalid",
+            "The record value is invalid.",
+        ));
+    }
+
+    if let Some(ttl) = params.u64("TTL")? {
+        if !(1..=604800).contains(&ttl) {
+            return Err(ApiError::new(
+                "LimitExceeded.RecordTtlLimit",
+                "The TTL value of the record exceeds the limit.",
+            ));
+        }
+        record.TTL = ttl;
+    }
+    if let Some(mx) = params.u64("MX")? {
+        record.MX = Some(mx);
+    }
+    if record.Type == "MX" && !matches!(record.MX, Some(1..=50)) {
+        return Err(ApiError::new(
+            "InvalidParameter.MxInvalid",
+            "The MX priority is invalid.",
+        ));
+    }
+    if let Some(weight) = params.u64("Weight")? {
+        if weight > 100 {
+            return Err(ApiError::new(
+                "InvalidParameter.InvalidWeight",
+                "The weight is invalid. Please enter an integer between 0 and 100.",
+            ));
+        }
+        record.Weight = Some(weight);
+    }
+    if let Some(status) = params.str("Status") {
+        if status != "ENABLE" && status != "DISABLE" {
+            return Err(ApiError::invalid_value("Status"));
+        }
+        record.Status = status;
+    }
+    if let Some(remark) = params.str("Remark") {
+        record.Remark = remark;
+    }
+    Ok(())
+}
+
+fn domain_list_item(d: &Domain) -> DomainListItem {
+    DomainListItem {
+        DomainId: d.id,
+        Name: d.name.clone(),
+        Status: "ENABLE".into(),
+        TTL: 600,
+        CNAMESpeedup: "DISABLE".into(),
+        DNSStatus: "".into(),
+        Grade: "DP_FREE".into(),
+        GroupId: 1,
+        SearchEnginePush: "NO".into(),
+        Remark: "".into(),
+        Punycode: d.name.clone(),
+        EffectiveDNS: NAME_SERVERS.iter().map(|ns| ns.to_string()).collect(),
+        GradeLevel: 5,
+        GradeTitle: "免费版".into(),
+        IsVip: "NO".into(),
+        VipStartAt: "0000-00-00 00:00:00".into(),
+        VipEndAt: "0000-00-00 00:00:00".into(),
+        VipAutoRenew: "DEFAULT".into(),
+        RecordCount: d.records.len() as u64,
+        CreatedOn: d.created_on.clone(),
+        UpdatedOn: d.updated_on.clone(),
+        Owner: "mock@dnspod.cn".into(),
+        TagList: Some(Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: &str = "2023-09-01 12:00:00";
+
+    fn params(value: Value) -> Params {
+        Params::from_json(value.to_string().as_bytes()).unwrap()
+    }
+
+    fn store() -> Store {
+        let mut store = Store::default();
+        store.add_domain("example.com", NOW).unwrap();
+        store
+    }
+
+    #[test]
+    fn query_params() {
+        let params = Params::from_query("Domain=example.com&RecordId=12&Subdomain=%E4%BD%A0+a");
+        assert_eq!(params.str("Domain").as_deref(), Some("example.com"));
+        assert_eq!(params.u64("RecordId"), Ok(Some(12)));
+        assert_eq!(params.str("Subdomain").as_deref(), Some("你 a"));
+        let params = Params::from_query("RecordType.0=A&RecordType.1=AAAA&IsExactSubDomain=true");
+        assert_eq!(params.list("RecordType"), ["A", "AAAA"]);
+        assert_eq!(params.bool("IsExactSubDomain"), Ok(Some(true)));
+        assert_eq!(
+            params.required("Value").unwrap_err().code,
+            "MissingParameter"
+        );
+    }
+
+    #[test]
+    fn create_and_list_records() {
+        let mut store = store();
+        let res = store
+            .create_record(
+                &params(json!({
+                    "Domain": "example.com",
+                    "SubDomain": "www",
+                    "RecordType": "A",
+                    "RecordLine": "默认",
+                    "Value": "1.1.1.1",
+                })),
+                NOW,
+            )
+            .unwrap();
+        let id = res["RecordId"].as_u64().unwrap();
+
+        let res = store
+            .describe_record_list(&params(
+                json!({"Domain": "example.com", "Subdomain": "www"}),
+            ))
+            .unwrap();
+        assert_eq!(res["RecordCountInfo"]["TotalCount"], 1);
+        assert_eq!(res["RecordList"][0]["RecordId"], id);
+        assert_eq!(res["RecordList"][0]["Value"], "1.1.1.1");
+
+        // 默认的 NS 记录
+        let res = store
+            .describe_record_list(&params(json!({"Domain": "example.com", "Subdomain": ""})))
+            .unwrap();
+        assert_eq!(res["RecordCountInfo"]["TotalCount"], 3);
+
+        let err = store
+            .describe_record_list(&params(
+                json!({"Domain": "example.com", "Subdomain": "mail"}),
+            ))
+            .unwrap_err();
+        assert_eq!(err.code, "ResourceNotFound.NoDataOfRecord");
+    }
+
+    #[test]
+    fn filter_records() {
+        let mut store = store();
+        let create = |store: &mut Store, value: Value| {
+            store
+                .create_record(&params(value), "2023-09-02 12:00:00")
+                .unwrap()
+        };
+        create(
+            &mut store,
+            json!({"Domain": "example.com", "SubDomain": "www", "RecordType": "A", "RecordLine": "默认", "Value": "1.1.1.1", "TTL": 600}),
+        );
+        create(
+            &mut store,
+            json!({"Domain": "example.com", "SubDomain": "www2", "RecordType": "AAAA", "RecordLine": "电信", "Value": "::1", "TTL": 60}),
+        );
+        create(
+            &mut store,
+            json!({"Domain": "example.com", "SubDomain": "@", "RecordType": "MX", "RecordLine": "默认", "Value": "mail.example.com.", "MX": 10, "Status": "DISABLE", "Remark": "mail"}),
+        );
+        let names = |res: ApiResult| -> Vec<String> {
+            res.unwrap()["RecordList"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|r| r["Name"].as_str().unwrap().to_string())
+                .collect()
+        };
+
+        let domain_id = store.domain("example.com").unwrap().id;
+        let res = store.describe_record_list(&params(json!({
+            "DomainId": domain_id,
+            "RecordLineId": "10=0",
+            "SortField": "name",
+            "SortType": "DESC",
+        })));
+        assert_eq!(names(res), ["www2"]);
+
+        let filter = |value: Value| store.describe_record_filter_list(&params(value));
+        assert_eq!(
+            names(filter(json!({"Domain": "example.com", "SubDomain": "www"}))),
+            ["www", "www2"]
+        );
+        assert_eq!(
+            names(filter(
+                json!({"Domain": "example.com", "SubDomain": "www", "IsExactSubDomain": true})
+            )),
+            ["www"]
+        );
+        assert_eq!(
+            names(filter(
+                json!({"Domain": "example.com", "RecordType": ["A", "AAAA"], "SortField": "ttl"})
+            )),
+            ["www2", "www"]
+        );
+        assert_eq!(
+            names(filter(
+                json!({"Domain": "example.com", "RecordStatus": ["DISABLE"], "MXBegin": 5, "Remark": "mail"})
+            )),
+            ["@"]
+        );
+        assert_eq!(
+            names(filter(
+                json!({"Domain": "example.com", "TTLEnd": 100, "UpdatedAtBegin": "2023-09-02"})
+            )),
+            ["www2"]
+        );
+        let err =
+            filter(json!({"Domain": "example.com", "UpdatedAtEnd": "2023-08-31"})).unwrap_err();
+        assert_eq!(err.code, "ResourceNotFound.NoDataOfRecord");
+        let err = filter(json!({"Domain": "example.com", "SortField": "id"})).unwrap_err();
+        assert_eq!(err.code, "InvalidParameterValue");
+    }
+
+    #[test]
+    fn invalid_records() {
+        let mut store = store();
+        let create = |store: &mut Store, value: Value| store.create_record(&params(value), NOW);
+
+        let err = create(
+            &mut store,
+            json!({"Domain": "example.com", "RecordType": "A", "RecordLine": "默认", "Value": "abc"}),
+        )
+        .unwrap_err();
+        assert_eq!(err.code, "InvalidParameter.RecordValueInvalid");
+
+        let err = create(
+            &mut store,
+            json!({"Domain": "example.com", "RecordType": "MX", "RecordLine": "默认", "Value": "mail.example.com."}),
+        )
+        .unwrap_err();
+        assert_eq!(err.code, "InvalidParameter.MxInvalid");
+
+        let err = create(
+            &mut store,
+            json!({"Domain": "example.org", "RecordType": "A", "RecordLine": "默认", "Value": "1.1.1.1"}),
+        )
+        .unwrap_err();
+        assert_eq!(err.code, "InvalidParameterValue.DomainNotExists");
+
+        let record = json!({"Domain": "example.com", "RecordType": "A", "RecordLine": "默认", "Value": "1.1.1.1"});
+        create(&mut store, record.clone()).unwrap();
+        let err = create(&mut store, record).unwrap_err();
+        assert_eq!(err.code, "InvalidParameter.DomainRecordExist");
+    }
+
+    #[test]
+    fn modify_and_delete_record() {
+        let mut store = store();
+        let id = store
+            .add_record("example.com", "www", "A", "1.1.1.1", NOW)
+            .unwrap();
+
+        store
+            .modify_dynamic_dns(
+                &params(json!({
+                    "Domain": "example.com",
+                    "SubDomain": "www",
+                    "RecordId": id,
+                    "RecordLine": "默认",
+                    "Value": "2.2.2.2",
+                    "Ttl": 60,
+                })),
+                "2023-09-02 12:00:00",
+            )
+            .unwrap();
+        let res = store
+            .describe_record(&params(json!({"Domain": "example.com", "RecordId": id})))
+            .unwrap();
+        assert_eq!(res["RecordInfo"]["Value"], "2.2.2.2");
+        assert_eq!(res["RecordInfo"]["TTL"], 60);
+        assert_eq!(res["RecordInfo"]["UpdatedOn"], "2023-09-02 12:00:00");
+
+        store
+            .delete_record(&params(json!({"Domain": "example.com", "RecordId": id})))
+            .unwrap();
+        let err = store
+            .delete_record(&params(json!({"Domain": "example.com", "RecordId": id})))
+            .unwrap_err();
+        assert_eq!(err.code, "InvalidParameter.RecordIdInvalid");
+    }
+
+    #[test]
+    fn modify_record_fields() {
+        let mut store = store();
+        let id = store
+            .add_record("example.com", "www", "A", "1.1.1.1", NOW)
+            .unwrap();
+        let record = |store: &Store| {
+            store
+                .describe_record(&params(json!({"Domain": "example.com", "RecordId": id})))
+                .unwrap()["RecordInfo"]
+                .clone()
+        };
+
+        let status = |status| json!({"Domain": "example.com", "RecordId": id, "Status": status});
+        store
+            .modify_record_status(&params(status("DISABLE")), NOW)
+            .unwrap();
+        assert_eq!(record(&store)["Enabled"], 0);
+        let err = store
+            .modify_record_status(&params(status("PAUSE")), NOW)
+            .unwrap_err();
+        assert_eq!(err.code, "InvalidParameterValue");
+
+        store
+            .modify_record_remark(
+                &params(json!({"Domain": "example.com", "RecordId": id, "Remark": "web"})),
+                NOW,
+            )
+            .unwrap();
+        assert_eq!(record(&store)["Remark"], "web");
+
+        let fields = |fields: Value| {
+            params(json!({"Domain": "example.com", "RecordId": id, "FieldList": fields}))
+        };
+        let res = store
+            .modify_record_fields(
+                &fields(json!([{"Key": "ttl", "Value": "120"}, {"Key": "record_line_id", "Value": "10=0"}])),
+                NOW,
+            )
+            .unwrap();
+        assert_eq!(res, json!({ "RecordId": id }));
+        let info = record(&store);
+        assert_eq!(
+            (info["TTL"].clone(), info["RecordLine"].clone()),
+            (json!(120), json!("电信"))
+        );
+        assert_eq!(
+            (info["Value"].clone(), info["Enabled"].clone()),
+            (json!("1.1.1.1"), json!(0))
+        );
+
+        // 修改后的记录不合法时不会改变
+        let err = store
+            .modify_record_fields(
+                &fields(json!([{"Key": "record_type", "Value": "AAAA"}])),
+                NOW,
+            )
+            .unwrap_err();
+        assert_eq!(err.code, "InvalidParameter.RecordValueInvalid");
+        assert_eq!(record(&store)["RecordType"], "A");
+        let err = store
+            .modify_record_fields(&fields(json!([{"Key": "remark", "Value": "web"}])), NOW)
+            .unwrap_err();
+        assert_eq!(err.code, "InvalidParameterValue");
+        let err = store
+            .modify_record_fields(&fields(json!([])), NOW)
+            .unwrap_err();
+        assert_eq!(err.code, "MissingParameter");
+
+        let params =
+            Params::from_query("FieldList.0.Key=ttl&FieldList.0.Value=60&FieldList.1.Key=mx");
+        assert_eq!(
+            params.key_values("FieldList"),
+            [("ttl".into(), "60".into()), ("mx".into(), String::new())]
+        );
+    }
+}
@@ -1,6 +1,12 @@
 //! 定义各个 Action 请求
 //! 
-//! 添加具体 Action 时可以通过 `@[url = consts::DNSPOD_URL]` 覆盖掉默认公共参数。可以覆盖的还有 region 和 version
+//! 添加具体 Action 时可以通过 `@[url = consts::DNSPOD_URL]` 覆盖掉默认公共参数。可以覆盖的还有 region、version 和 method (如 `@[method = Method::GET]`)
+//! 
+//! 不是幂等的 Action (如 `CreateRecord`) 需要标记 `@[idempotent = false]`, 失败时默认不会重试, 见 [`RetryPolicy`](crate::client::RetryPolicy)
+//! 
+//! 发送前需要检查参数组合的 Action 可以通过 `@[validate = validate_record]` 指定检查函数, 签名为 `fn(&Self) -> Result<()>`
+//! 
+//! 通过 `@[response = response::RecordListResponse]` 指定请求的返回结果类型, 即 [`ExtractOutput::Output`](crate::ExtractOutput::Output)
 //! 
 
 #![allow(non_snake_case)]
@@ -10,8 +16,10 @@ use crate::utils::none_to_empty_string;
 
 use crate::data_types::*;
 use crate::consts;
+use crate::response;
 
 /// for `#[serde(crate = "dnspod_lib::serde")]`
+#[allow(unused_imports)]
 mod dnspod_lib {
     pub use crate::data_types;
     pub use crate::serde;
@@ -29,11 +37,30 @@ macro_rules! overloading_common_params {
     (region = $expr: expr) => {
         #[inline] fn region(&self) -> Option<$crate::data_types::Region> { Some($expr) }
     };
+    (method = $expr: expr) => {
+        #[inline] fn method(&self) -> $crate::transport::Method { $expr }
+    };
+    (idempotent = $expr: expr) => {
+        #[inline] fn idempotent(&self) -> bool { $expr }
+    };
+    (validate = $expr: expr) => {
+        #[inline] fn validate(&self) -> $crate::Result<()> { ($expr)(self) }
+    };
+    // 由 `output_type!` 处理
+    (response = $ty: ty) => {};
     ($($tt: tt)*) => {
-        compile_error!("This macro only accepts `url` `region` `version`");
+        compile_error!("This macro only accepts `url` `region` `version` `method` `idempotent` `validate` `response`");
     };
 }
 
+/// 从 `@[..]` 列表中找出 `response = ...`, 没有则为 `serde_json::Value`
+#[macro_export]
+macro_rules! output_type {
+    () => { $crate::serde_json::Value };
+    ([response = $ty: ty] $($tail: tt)*) => { $ty };
+    ([$($my_meta: tt)*] $($tail: tt)*) => { $crate::output_type!($($tail)*) };
+}
+
 #[macro_export]
 macro_rules! impl_define_action_list {
     (
@@ -53,11 +80,17 @@ macro_rules! impl_define_action_list {
 
                 impl ExtractCommonParams for $name {
                     #[inline] fn action(&self) -> &'static str { stringify!($name) }
-                    #[inline] fn body(&self) -> Vec<u8> { serde_json::to_vec(self).unwrap() }
+                    #[inline] fn try_body(&self) -> $crate::Result<Vec<u8>> {
+                        serde_json::to_vec(self).map_err($crate::Error::Serialize)
+                    }
                     $(
                         $crate::overloading_common_params! { $($my_meta)* }
                     )*
                 }
+
+                impl $crate::ExtractOutput for $name {
+                    type Output = $crate::output_type!($([$($my_meta)*])*);
+                }
             };
         )*
     };
@@ -87,6 +120,8 @@ macro_rules! impl_define_action_list {
         )*
 
         $(#[$enum_meta])*
+        // 每个变体就是对应的请求, 不装箱, 方便直接匹配
+        #[allow(clippy::large_enum_variant)]
         $enum_vis enum $enum_name {
             $($name($name),)*
         }
@@ -101,11 +136,11 @@ macro_rules! impl_define_action_list {
                 }
             }
             #[inline]
-            fn body(&self) -> Vec<u8> {
+            fn try_body(&self) -> $crate::Result<Vec<u8>> {
                 #[allow(unreachable_patterns)]
                 match self {
-                    $(Self::$name(v) => v.body(), )*
-                    _ => Default::default(),
+                    $(Self::$name(v) => v.try_body(), )*
+                    _ => Ok(Default::default()),
                 }
             }
             #[inline]
@@ -132,6 +167,34 @@ macro_rules! impl_define_action_list {
                     _ => Default::default(),
                 }
             }
+            #[inline]
+            fn method(&self) -> $crate::transport::Method {
+                #[allow(unreachable_patterns)]
+                match self {
+                    $(Self::$name(v) => v.method(), )*
+                    _ => Default::default(),
+                }
+            }
+            #[inline]
+            fn idempotent(&self) -> bool {
+                #[allow(unreachable_patterns)]
+                match self {
+                    $(Self::$name(v) => v.idempotent(), )*
+                    _ => true,
+                }
+            }
+            #[inline]
+            fn validate(&self) -> $crate::Result<()> {
+                #[allow(unreachable_patterns)]
+                match self {
+                    $(Self::$name(v) => v.validate(), )*
+                    _ => Ok(()),
+                }
+            }
+        }
+
+        impl $crate::ExtractOutput for $enum_name {
+            type Output = $crate::serde_json::Value;
         }
     };
 }
@@ -185,6 +248,8 @@ crate::custom_meta_struct! {
     /// <https://cloud.tencent.com/document/api/1427/56172>
     @[url = consts::DNSPOD_URL]
     @[version = Version::Version2021_03_23]
+    @[response = response::DomainListResponse]
+    #[derive(Default)]
     pub struct DescribeDomainList {
         /// 域名分组类型，默认为ALL
         #[cfg_attr(feature = "clap", arg(long, value_enum, default_value_t=Default::default()))]
@@ -208,15 +273,22 @@ crate::custom_meta_struct! {
     }
 
     /// 添加域名 <https://cloud.tencent.com/document/api/1427/56184>
+    @[response = response::CreateDomainResponse]
+    @[idempotent = false]
     pub struct CreateDomain {
         Domain: String,
     }
     /// 删除域名 <https://cloud.tencent.com/document/api/1427/56178>
+    @[response = response::EmptyResponse]
     pub struct DeleteDomain {
         Domain: String,
     }
 
     /// 添加记录 <https://cloud.tencent.com/document/api/1427/56180>
+    @[response = response::RecordIdResponse]
+    @[idempotent = false]
+    @[validate = |r: &Self| validate_record(&r.RecordType, r.MX, r.TTL, r.Weight)]
+    #[derive(Default)]
     pub struct CreateRecord {
         /// 域名
         /// 示例值：dnspod.cn
@@ -238,10 +310,51 @@ crate::custom_meta_struct! {
         /// 示例值：200.200.200.200
         #[cfg_attr(feature = "clap", arg(long))]
         pub Value: String,
+        /// 域名 ID 。参数 DomainId 优先级比参数 Domain 高，如果传递参数 DomainId 将忽略参数 Domain 。
+        /// 示例值：1923
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub DomainId: Option<Integer>,
+        /// 线路的 ID，通过 API 记录线路获得，英文字符串，比如：10=1。参数 RecordLineId 优先级高于 RecordLine，如果同时传递二者，优先使用 RecordLineId 参数。
+        /// 示例值：10=1
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub RecordLineId: Option<String>,
+        /// MX 优先级，当记录类型是 MX 时有效，范围 1-50，MX 记录时必选。
+        /// 示例值：10
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub MX: Option<Integer>,
+        /// TTL，范围 1-604800，不同等级域名最小值不同。
+        /// 示例值：600
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub TTL: Option<Integer>,
+        /// 权重信息，0 到 100 的整数。0 表示关闭，不传该参数，表示不设置权重信息。
+        /// 示例值：20
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Weight: Option<Integer>,
+        /// 记录初始状态，取值范围为 ENABLE 和 DISABLE 。默认为 ENABLE ，如果传入 DISABLE，解析不会生效，也不会验证负载均衡的限制。
+        /// 示例值：DISABLE
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub Status: Option<RecordStatus>,
+        /// 备注
+        /// 示例值：dnspod
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Remark: Option<String>,
+        /// 记录分组 Id。可以通过接口 DescribeRecordGroupList 接口 GroupId 字段获取。
+        /// 示例值：1
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub GroupId: Option<Integer>,
     }
 
     /// 删除记录
     /// <https://cloud.tencent.com/document/api/1427/56176>
+    @[response = response::EmptyResponse]
     pub struct DeleteRecord {
         /// 域名
         /// 示例值：dnspod.cn
@@ -255,25 +368,199 @@ crate::custom_meta_struct! {
 
     /// 获取域名的解析记录列表
     /// <https://cloud.tencent.com/document/api/1427/56166>
+    @[response = response::RecordListResponse]
+    #[derive(Default)]
     pub struct DescribeRecordList {
         /// 要获取的解析记录所属的域名
         /// 示例值：example.com
         #[cfg_attr(feature = "clap", arg(long))]
         pub Domain: String,
+        /// 要获取的解析记录所属的域名Id，如果传了DomainId，系统将会忽略Domain参数
+        /// 示例值：62
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub DomainId: Option<Integer>,
         /// 解析记录的主机头，如果传了此参数，则只会返回此主机头对应的解析记录
         /// 示例值：www
         #[serde(serialize_with = "none_to_empty_string")]
         #[cfg_attr(feature = "clap", arg(long, default_value=""))]
         pub Subdomain: Option<String>,
+        /// 获取某种类型的解析记录，如 A，CNAME，NS，AAAA，显性URL，隐性URL，CAA，SPF等
+        /// 示例值：A
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub RecordType: Option<RecordType>,
+        /// 获取某条线路名称的解析记录
+        /// 示例值：默认
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub RecordLine: Option<String>,
+        /// 获取某个线路Id对应的解析记录，如果传RecordLineId，系统会忽略RecordLine参数
+        /// 示例值：10=0
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub RecordLineId: Option<String>,
+        /// 获取某个分组下的解析记录时，传这个分组Id
+        /// 示例值：2
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub GroupId: Option<Integer>,
         /// 通过关键字搜索解析记录，当前支持搜索主机头和记录值
         /// 示例值：book
         #[serde(skip_serializing_if = "Option::is_none")]
         #[cfg_attr(feature = "clap", arg(long, default_value=""))]
         pub Keyword: Option<String>,
+        /// 排序字段，支持 name,line,type,value,weight,mx,ttl,updated_on 几个字段。
+        /// 示例值：updated_on
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub SortField: Option<SortField>,
+        /// 排序方式，正序：ASC，逆序：DESC。默认值为ASC。
+        /// 示例值：DESC
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub SortType: Option<SortType>,
+        /// 偏移量，默认值为0。
+        /// 示例值：0
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Offset: Option<Integer>,
+        /// 限制数量，当前Limit最大支持3000。默认值为100。
+        /// 示例值：100
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Limit: Option<Integer>,
+    }
+
+    /// 获取某个域名下的解析记录列表, 比 [DescribeRecordList] 支持更多的筛选条件
+    /// <https://cloud.tencent.com/document/api/1427/84069>
+    @[response = response::RecordListResponse]
+    #[derive(Default)]
+    pub struct DescribeRecordFilterList {
+        /// 要获取的解析记录所属的域名
+        /// 示例值：example.com
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Domain: String,
+        /// 要获取的解析记录所属的域名 Id，如果传了 DomainId，系统将会忽略 Domain 参数
+        /// 示例值：62
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub DomainId: Option<Integer>,
+        /// 根据解析记录的主机头获取解析记录。默认模糊匹配。可以通过设置 IsExactSubdomain 参数为 true 进行精确查找。
+        /// 示例值：www
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub SubDomain: Option<String>,
+        /// 获取某些类型的解析记录，如 A，CNAME，NS，AAAA，显性URL，隐性URL，CAA，SPF等
+        /// 示例值：["A", "AAAA"]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub RecordType: Vec<RecordType>,
+        /// 获取某些线路名称的解析记录
+        /// 示例值：["默认"]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub RecordLine: Vec<String>,
+        /// 获取某些分组下的解析记录时，传这个分组 Id
+        /// 示例值：[1]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub GroupId: Vec<Integer>,
+        /// 通过关键字搜索解析记录，当前支持搜索主机头和记录值
+        /// 示例值：book
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Keyword: Option<String>,
+        /// 排序字段，支持 name,line,type,value,weight,mx,ttl,updated_on 几个字段。
+        /// 示例值：updated_on
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub SortField: Option<SortField>,
+        /// 排序方式，升序：ASC，降序：DESC。默认值为ASC。
+        /// 示例值：DESC
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub SortType: Option<SortType>,
+        /// 偏移量，默认值为0。
+        /// 示例值：0
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Offset: Option<Integer>,
+        /// 限制数量，当前Limit最大支持3000。默认值为100。
+        /// 示例值：100
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Limit: Option<Integer>,
+        /// 根据解析记录的值获取解析记录
+        /// 示例值：1.1.1.1
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub RecordValue: Option<String>,
+        /// 根据解析记录的状态获取解析记录。可取值为 ENABLE，DISABLE。
+        /// 示例值：["ENABLE"]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub RecordStatus: Vec<RecordStatus>,
+        /// 要获取解析记录权重查询区间起点
+        /// 示例值：10
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub WeightBegin: Option<Integer>,
+        /// 要获取解析记录权重查询区间终点
+        /// 示例值：100
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub WeightEnd: Option<Integer>,
+        /// 要获取解析记录 MX 优先级查询区间起点
+        /// 示例值：10
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub MXBegin: Option<Integer>,
+        /// 要获取解析记录 MX 优先级查询区间终点
+        /// 示例值：20
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub MXEnd: Option<Integer>,
+        /// 要获取解析记录 TTL 查询区间起点
+        /// 示例值：600
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub TTLBegin: Option<Integer>,
+        /// 要获取解析记录 TTL 查询区间终点
+        /// 示例值：86400
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub TTLEnd: Option<Integer>,
+        /// 要获取解析记录更新时间查询区间起点
+        /// 示例值：2021-09-07
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub UpdatedAtBegin: Option<Date>,
+        /// 要获取解析记录更新时间查询区间终点
+        /// 示例值：2021-09-07
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub UpdatedAtEnd: Option<Date>,
+        /// 根据解析记录的备注获取解析记录
+        /// 示例值：用于api
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Remark: Option<String>,
+        /// 是否根据 Subdomain 参数进行精确查找。
+        /// 示例值：false
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub IsExactSubDomain: Option<Boolean>,
+        /// 项目 ID
+        /// 示例值：-1
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub ProjectId: Option<i64>,
     }
 
     /// 获取记录信息
     /// <https://cloud.tencent.com/document/api/1427/56168>
+    @[response = response::RecordInfoResponse]
     pub struct DescribeRecord {
         /// 域名
         /// 示例值：dnspod.cn
@@ -286,6 +573,7 @@ crate::custom_meta_struct! {
     }
 
     /// 获取等级允许的记录类型 <https://cloud.tencent.com/document/api/1427/56165>
+    @[response = response::RecordTypeResponse]
     pub struct DescribeRecordType {
         /// 域名等级。
         /// 
@@ -298,6 +586,7 @@ crate::custom_meta_struct! {
         pub DomainGrade: DomainGrade,
     }
     /// 获取等级允许的线路 <https://cloud.tencent.com/document/api/1427/56167>
+    @[response = response::RecordLineListResponse]
     pub struct DescribeRecordLineList {
         /// 域名。
         /// 示例值：dnspod.cn
@@ -309,6 +598,7 @@ crate::custom_meta_struct! {
 
     /// 更新动态 DNS 记录
     /// <https://cloud.tencent.com/document/api/1427/56158>
+    @[response = response::RecordIdResponse]
     pub struct ModifyDynamicDNS {
         /// 域名
         /// 示例值：dnspod.cn
@@ -336,8 +626,80 @@ crate::custom_meta_struct! {
         pub Ttl: Integer,
     }
 
+    /// 修改解析记录的状态
+    /// <https://cloud.tencent.com/document/api/1427/56169>
+    @[response = response::RecordIdResponse]
+    pub struct ModifyRecordStatus {
+        /// 域名
+        /// 示例值：dnspod.cn
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Domain: String,
+        /// 记录 ID 。可以通过接口 [DescribeRecordList] 查到所有的解析记录列表以及对应的 RecordId
+        /// 示例值：162
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub RecordId: u64,
+        /// 记录的状态。取值范围为 ENABLE 和 DISABLE。如果传入 DISABLE，解析不会生效，也不会验证负载均衡的限制。
+        /// 示例值：DISABLE
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub Status: RecordStatus,
+        /// 域名 ID 。参数 DomainId 优先级比参数 Domain 高，如果传递参数 DomainId 将忽略参数 Domain 。
+        /// 示例值：1923
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub DomainId: Option<Integer>,
+    }
+
+    /// 设置记录备注
+    /// <https://cloud.tencent.com/document/api/1427/56170>
+    @[response = response::EmptyResponse]
+    pub struct ModifyRecordRemark {
+        /// 域名
+        /// 示例值：dnspod.cn
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Domain: String,
+        /// 记录 ID 。可以通过接口 [DescribeRecordList] 查到所有的解析记录列表以及对应的 RecordId
+        /// 示例值：162
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub RecordId: u64,
+        /// 解析记录备注，删除备注请提交空内容。
+        /// 示例值：这是备注
+        #[cfg_attr(feature = "clap", arg(long, default_value = ""))]
+        pub Remark: String,
+        /// 域名 ID 。参数 DomainId 优先级比参数 Domain 高，如果传递参数 DomainId 将忽略参数 Domain 。
+        /// 示例值：1923
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub DomainId: Option<Integer>,
+    }
+
+    /// 修改记录的部分字段, 未列出的字段保持不变
+    /// <https://cloud.tencent.com/document/api/1427/105622>
+    @[response = response::RecordIdResponse]
+    pub struct ModifyRecordFields {
+        /// 域名
+        /// 示例值：dnspod.cn
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Domain: String,
+        /// 记录 ID 。可以通过接口 [DescribeRecordList] 查到所有的解析记录列表以及对应的 RecordId
+        /// 示例值：162
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub RecordId: u64,
+        /// 要修改的记录属性和值，支持：sub_domain，record_line，record_line_id，record_type，value，ttl，status，mx，weight
+        /// 示例值：ttl=600
+        #[cfg_attr(feature = "clap", arg(long = "field", required = true))]
+        pub FieldList: Vec<KeyValue>,
+        /// 域名 ID 。参数 DomainId 优先级比参数 Domain 高，如果传递参数 DomainId 将忽略参数 Domain 。
+        /// 示例值：1923
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub DomainId: Option<Integer>,
+    }
+
     /// 修改记录
     /// <https://cloud.tencent.com/document/api/1427/56157>
+    @[response = response::RecordIdResponse]
+    @[validate = |r: &Self| validate_record(&r.RecordType, r.MX, r.TTL, r.Weight)]
+    #[derive(Default)]
     pub struct ModifyRecord {
         /// 域名
         /// 示例值：dnspod.cn
@@ -363,12 +725,87 @@ crate::custom_meta_struct! {
         /// 示例值：200.200.200.200
         #[cfg_attr(feature = "clap", arg(long))]
         pub Value: String,
+        /// 域名 ID 。参数 DomainId 优先级比参数 Domain 高，如果传递参数 DomainId 将忽略参数 Domain 。
+        /// 示例值：1923
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub DomainId: Option<Integer>,
+        /// 线路的 ID，通过 API 记录线路获得，英文字符串，比如：10=1。参数 RecordLineId 优先级高于 RecordLine，如果同时传递二者，优先使用 RecordLineId 参数。
+        /// 示例值：10=1
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub RecordLineId: Option<String>,
+        /// MX 优先级，当记录类型是 MX 时有效，范围 1-50，MX 记录时必选。
+        /// 示例值：10
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub MX: Option<Integer>,
+        /// TTL，范围 1-604800，不同等级域名最小值不同。
+        /// 示例值：600
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub TTL: Option<Integer>,
+        /// 权重信息，0 到 100 的整数。0 表示关闭，不传该参数，表示不设置权重信息。
+        /// 示例值：20
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Weight: Option<Integer>,
+        /// 记录状态，取值范围为 ENABLE 和 DISABLE 。如果传入 DISABLE，解析不会生效，也不会验证负载均衡的限制。
+        /// 示例值：DISABLE
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long, value_enum))]
+        pub Status: Option<RecordStatus>,
+        /// 备注
+        /// 示例值：dnspod
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub Remark: Option<String>,
+        /// 记录分组 Id。可以通过接口 DescribeRecordGroupList 接口 GroupId 字段获取。
+        /// 示例值：1
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "clap", arg(long))]
+        pub GroupId: Option<Integer>,
     }
 }
 
 
 
+/// 检查 [`CreateRecord`] 和 [`ModifyRecord`] 的参数组合: MX 记录必须有 1-50 的优先级.
+/// 其他类型设置的 MX 会被 DNSPod 忽略, 不做检查
+fn validate_record(
+    record_type: &RecordType,
+    mx: Option<Integer>,
+    ttl: Option<Integer>,
+    weight: Option<Integer>,
+) -> crate::Result<()> {
+    use crate::Error::InvalidParams;
+
+    match (record_type, mx) {
+        (RecordType::MX, None) => {
+            return Err(InvalidParams("MX record requires `MX` priority".into()));
+        }
+        (RecordType::MX, Some(mx)) if !(1..=50).contains(&mx) => {
+            return Err(InvalidParams(format!(
+                "`MX` must be between 1 and 50, got {mx}"
+            )));
+        }
+        _ => {}
+    }
+    if let Some(ttl) = ttl.filter(|ttl| !(1..=604800).contains(ttl)) {
+        return Err(InvalidParams(format!(
+            "`TTL` must be between 1 and 604800, got {ttl}"
+        )));
+    }
+    if let Some(weight) = weight.filter(|&weight| weight > 100) {
+        return Err(InvalidParams(format!(
+            "`Weight` must be between 0 and 100, got {weight}"
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
+#[allow(dead_code)]
 mod tests {
     use super::*;
 
@@ -399,4 +836,175 @@ mod tests {
             pub Domain: String,
         }
     }    
-}
\ No newline at end of file
+
+    crate::define_action_list! {
+        /// json 的 key 只能是字符串, 无法序列化
+        #[allow(non_snake_case)]
+        pub struct Unserializable {
+            pub Keys: std::collections::HashMap<(u8, u8), u8>,
+        }
+    }
+
+    #[test]
+    fn serialize_error() {
+        use crate::client::Client;
+        use crate::credential::Credential;
+        use crate::transport::MemoryTransport;
+        use crate::{Error, ExtractCommonParams};
+
+        let request = Unserializable {
+            Keys: [((1, 2), 3)].into(),
+        };
+        assert!(matches!(request.try_body(), Err(Error::Serialize(_))));
+        assert!(matches!(request.try_query(), Err(Error::Serialize(_))));
+        assert!(matches!(
+            request.try_headers(&"id", &"key"),
+            Err(Error::Serialize(_))
+        ));
+        let options = crate::SignOptions {
+            method: Some(crate::transport::Method::GET),
+            ..Default::default()
+        };
+        assert!(request
+            .signed_headers(&Credential::new("id", "key"), &options)
+            .is_err());
+
+        let transport = MemoryTransport::new();
+        let client = Client::with_transport("id", "key", &transport);
+        let err = client.execute(request).unwrap_err();
+        assert!(matches!(err, Error::Serialize(_)));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[test]
+    fn skip_unset_filters() {
+        use crate::data_types::{RecordStatus, RecordType, SortField};
+        use crate::ExtractCommonParams;
+
+        fn body(request: impl ExtractCommonParams) -> serde_json::Value {
+            serde_json::from_slice(&request.try_body().unwrap()).unwrap()
+        }
+        let list = DescribeRecordList {
+            Domain: "example.com".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            body(list),
+            serde_json::json!({"Domain": "example.com", "Subdomain": ""})
+        );
+
+        let list = DescribeRecordFilterList {
+            Domain: "example.com".into(),
+            RecordType: vec![RecordType::A, RecordType::AAAA],
+            RecordStatus: vec![RecordStatus::DISABLE],
+            SortField: Some(SortField::UpdatedOn),
+            TTLEnd: Some(600),
+            ..Default::default()
+        };
+        assert_eq!(
+            body(list),
+            serde_json::json!({
+                "Domain": "example.com",
+                "RecordType": ["A", "AAAA"],
+                "RecordStatus": ["DISABLE"],
+                "SortField": "updated_on",
+                "TTLEnd": 600,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_record_params() {
+        use crate::client::Client;
+        use crate::transport::MemoryTransport;
+        use crate::{Error, ExtractCommonParams};
+
+        let mx = CreateRecord {
+            Domain: "example.com".into(),
+            RecordType: RecordType::MX,
+            Value: "mail.example.com.".into(),
+            ..Default::default()
+        };
+        assert!(matches!(mx.validate(), Err(Error::InvalidParams(_))));
+        let with = |mx_priority, ttl| CreateRecord {
+            MX: mx_priority,
+            TTL: ttl,
+            ..mx.clone()
+        };
+        assert!(with(Some(51), None).validate().is_err());
+        assert!(with(Some(10), Some(600)).validate().is_ok());
+
+        let a = ModifyRecord {
+            Domain: "example.com".into(),
+            RecordId: 1,
+            Value: "1.1.1.1".into(),
+            ..Default::default()
+        };
+        assert!(a.validate().is_ok());
+        assert!(ModifyRecord {
+            MX: Some(10),
+            ..a.clone()
+        }
+        .validate()
+        .is_ok());
+        for invalid in [
+            ModifyRecord {
+                TTL: Some(0),
+                ..a.clone()
+            },
+            ModifyRecord {
+                Weight: Some(101),
+                ..a.clone()
+            },
+        ] {
+            assert!(invalid.validate().is_err(), "{invalid:?}");
+        }
+
+        // 不合法的请求不会发送
+        let transport = MemoryTransport::new();
+        let client = Client::with_transport("id", "key", &transport);
+        let err = client.execute(mx).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid request: MX record requires `MX` priority"
+        );
+        assert!(transport.requests().is_empty());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_body_calls_try_body() {
+        use crate::ExtractCommonParams;
+
+        struct Empty;
+        impl ExtractCommonParams for Empty {
+            fn action(&self) -> &'static str {
+                "Empty"
+            }
+            fn try_body(&self) -> crate::Result<Vec<u8>> {
+                Ok(b"{}".to_vec())
+            }
+        }
+        #[allow(deprecated)]
+        let body = Empty.body();
+        assert_eq!(body, b"{}");
+        assert_eq!(Empty.try_query().unwrap(), "");
+    }
+
+    #[test]
+    fn query_from_non_json_body() {
+        use crate::{Error, ExtractCommonParams};
+
+        // 正文不是 json 时不能生成查询字符串, 不会不带参数发送
+        struct Form;
+        impl ExtractCommonParams for Form {
+            fn action(&self) -> &'static str {
+                "Form"
+            }
+            fn try_body(&self) -> crate::Result<Vec<u8>> {
+                Ok(b"Domain=example.com".to_vec())
+            }
+        }
+        assert!(matches!(Form.try_query(), Err(Error::Serialize(_))));
+    }
+}
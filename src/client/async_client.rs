@@ -0,0 +1,241 @@
+use std::fmt;
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+
+use futures_util::{stream, Stream};
+
+use super::paginate::Pager;
+use super::{Config, Paginate, RateLimiter, RetryPolicy, Sleeper};
+use crate::action::{DescribeDomainList, DescribeRecordList};
+use crate::clock::Clock;
+use crate::credential::{Credential, CredentialProvider};
+use crate::data_types::Language;
+use crate::error::Result;
+use crate::response::{DomainListItem, RecordListItem, Response};
+use crate::transport::{AsyncTransport, HttpRequest, HttpResponse, Method};
+use crate::{ExtractCommonParams, ExtractOutput};
+
+/// 异步 Client, 签名与返回结果的处理和 [`Client`](super::Client) 完全一致
+///
+/// ```no_run
+/// # use dnspod_lib::prelude::*;
+/// # #[cfg(feature = "async")]
+/// # async fn run() -> Result<(), dnspod_lib::Error> {
+/// let client = AsyncClient::new("your-secret-id", "your-secret-key");
+/// let res = client
+///     .execute(DescribeRecordList {
+///         Domain: "example.com".into(),
+///         Subdomain: None,
+///         Keyword: None,
+///         ..Default::default()
+///     })
+///     .await?;
+/// println!("RequestId: {}, records: {:?}", res.RequestId, res.RecordList);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AsyncClient<T> {
+    config: Config,
+    sleeper: Arc<dyn Sleeper>,
+    transport: T,
+}
+
+impl<T: fmt::Debug> fmt::Debug for AsyncClient<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncClient")
+            .field("transport", &self.transport)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncClient<crate::transport::AsyncReqwestTransport> {
+    pub fn new(secret_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self::with_client(secret_id, secret_key, reqwest::Client::new())
+    }
+
+    /// 使用自定义的 `reqwest::Client`, 比如设置了代理或超时
+    pub fn with_client(
+        secret_id: impl Into<String>,
+        secret_key: impl Into<String>,
+        client: reqwest::Client,
+    ) -> Self {
+        Self::with_transport(
+            secret_id,
+            secret_key,
+            crate::transport::AsyncReqwestTransport::new(client),
+        )
+    }
+}
+
+impl<T: AsyncTransport> AsyncClient<T> {
+    /// 使用自定义的 [`AsyncTransport`] 发送请求
+    pub fn with_transport(
+        secret_id: impl Into<String>,
+        secret_key: impl Into<String>,
+        transport: T,
+    ) -> Self {
+        Self::with_provider(Credential::new(secret_id, secret_key), transport)
+    }
+
+    /// 密钥来自 [`CredentialProvider`], 比如 [`ChainProvider`](crate::credential::ChainProvider), 每次请求时获取
+    pub fn with_provider(provider: impl CredentialProvider + 'static, transport: T) -> Self {
+        Self {
+            config: Config::new(provider),
+            sleeper: super::sleep::default_sleeper(),
+            transport,
+        }
+    }
+
+    /// 请求发送到 `endpoint`, 比如国际站 [`DNSPOD_INTL_URL`](crate::consts::DNSPOD_INTL_URL)、代理或本地的测试服务器,
+    /// 签名中的 `host` 也取自 `endpoint`. 默认为各个请求的 [`ExtractCommonParams::url`]
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.config.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// 使用 `method` 发送所有请求, 默认为各个请求的 [`ExtractCommonParams::method`]
+    pub fn with_method(mut self, method: Method) -> Self {
+        self.config.method = Some(method);
+        self
+    }
+
+    /// 服务器返回的错误信息 (`X-TC-Language`) 和 [`Error::Api`](crate::Error::Api) 中错误码描述的语言, 默认为英文
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.config.language = language;
+        self
+    }
+
+    /// 请求失败后的重试策略, 默认不重试
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.config.retry = retry;
+        self
+    }
+
+    /// 发送前按 [`RateLimiter`] 等待
+    ///
+    /// 限速不会按 SecretId 自动共享: 同一个账号的多个 Client 需要传入同一个 RateLimiter,
+    /// 比如都使用 [`RateLimiter::shared`]`(secret_id)`, 否则各自计数, 合计可能超出接口频率限制
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.config.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// 重试和限速时的等待方式, 默认见 [`Sleeper`]
+    pub fn with_sleeper(mut self, sleeper: impl Sleeper + 'static) -> Self {
+        self.sleeper = Arc::new(sleeper);
+        self
+    }
+
+    /// 签名使用的时间, 默认为系统时间
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.config.clock = Arc::new(clock);
+        self
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// 只签名不发送, 比如用 [`HttpRequest::to_curl`] 生成调试用的命令
+    pub fn sign(&self, request: &impl ExtractCommonParams) -> Result<HttpRequest> {
+        self.config.sign(request)
+    }
+
+    /// 服务器时间与本地时间的偏差, 服务器返回 `AuthFailure.SignatureExpire` 时根据其 `Date` 头部自动校正
+    pub fn clock_skew(&self) -> chrono::Duration {
+        self.config.skew()
+    }
+
+    /// 发送请求, 返回该请求对应的 [`ExtractOutput::Output`], 服务器返回 `Error` 时转换为 [`Error::Api`](crate::Error::Api)
+    pub async fn execute<R: ExtractOutput>(&self, request: R) -> Result<R::Output> {
+        let res: Response<R::Output> = self.execute_as(request).await?;
+        Ok(res.Response)
+    }
+
+    /// 把完整的返回结果(包括最外层的 `Response`)解析为任意类型, 比如 `serde_json::Value`
+    ///
+    /// 失败时按 [`RetryPolicy`] 重新签名并重试
+    pub async fn execute_as<O: DeserializeOwned>(
+        &self,
+        request: impl ExtractCommonParams,
+    ) -> Result<O> {
+        let mut attempt = 1;
+        loop {
+            match self.execute_once(&request).await {
+                Err(e) if self.config.retry.should_retry(&request, &e, attempt) => {
+                    self.sleep(self.config.retry.delay(attempt)).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// 自动翻页, 逐条返回 `request` 的列表中的条目, 同 [`Client::paginate`](super::Client::paginate)
+    pub fn paginate<'a, R: Paginate + 'a>(
+        &'a self,
+        request: R,
+    ) -> impl Stream<Item = Result<R::Item>> + 'a {
+        stream::unfold(Pager::new(request), move |mut pager| async move {
+            loop {
+                if let Some(item) = pager.pop() {
+                    return Some((Ok(item), pager));
+                }
+                let request = pager.next_request()?;
+                if let Err(e) = pager.push(self.execute(request).await) {
+                    return Some((Err(e), pager));
+                }
+            }
+        })
+    }
+
+    /// 符合 `filter` 的所有域名
+    pub fn domains(
+        &self,
+        filter: DescribeDomainList,
+    ) -> impl Stream<Item = Result<DomainListItem>> + '_ {
+        self.paginate(filter)
+    }
+
+    /// `domain` 下符合 `filter` 的所有记录, `filter` 中的 `Domain` 会被替换为 `domain`
+    pub fn records(
+        &self,
+        domain: impl Into<String>,
+        filter: DescribeRecordList,
+    ) -> impl Stream<Item = Result<RecordListItem>> + '_ {
+        self.paginate(DescribeRecordList {
+            Domain: domain.into(),
+            ..filter
+        })
+    }
+
+    async fn execute_once<O: DeserializeOwned>(
+        &self,
+        request: &impl ExtractCommonParams,
+    ) -> Result<O> {
+        let res = self.send(request).await?;
+        match super::parse_response(&res.body, self.config.language) {
+            // 本地时间不准, 按服务器时间重新签名
+            Err(e) if self.config.correct_skew(&e, &res) => {
+                let res = self.send(request).await?;
+                super::parse_response(&res.body, self.config.language)
+            }
+            res => res,
+        }
+    }
+
+    async fn send(&self, request: &impl ExtractCommonParams) -> Result<HttpResponse> {
+        self.sleep(self.config.throttle(request)).await;
+        let request = self.config.sign(request)?;
+        self.transport.send(request).await
+    }
+
+    async fn sleep(&self, duration: std::time::Duration) {
+        if !duration.is_zero() {
+            self.sleeper.sleep(duration).await;
+        }
+    }
+}
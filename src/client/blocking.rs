@@ -0,0 +1,215 @@
+use std::fmt;
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+
+use super::{Config, Pages, Paginate, RateLimiter, RetryPolicy};
+use crate::action::{DescribeDomainList, DescribeRecordList};
+use crate::clock::Clock;
+use crate::credential::{Credential, CredentialProvider};
+use crate::data_types::Language;
+use crate::error::Result;
+use crate::response::Response;
+use crate::transport::{HttpRequest, HttpResponse, Method, Transport};
+use crate::{ExtractCommonParams, ExtractOutput};
+
+/// 同步 Client
+///
+/// ```no_run
+/// # use dnspod_lib::prelude::*;
+/// # #[cfg(not(feature = "blocking"))] fn main() {}
+/// # #[cfg(feature = "blocking")]
+/// # fn main() -> Result<(), dnspod_lib::Error> {
+/// let client = Client::new("your-secret-id", "your-secret-key");
+/// let res = client.execute(DescribeRecordList {
+///     Domain: "example.com".into(),
+///     Subdomain: None,
+///     Keyword: None,
+///     ..Default::default()
+/// })?;
+/// println!("RequestId: {}, records: {:?}", res.RequestId, res.RecordList);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Client<T> {
+    config: Config,
+    transport: T,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Client<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("transport", &self.transport)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Client<crate::transport::ReqwestTransport> {
+    pub fn new(secret_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self::with_client(secret_id, secret_key, reqwest::blocking::Client::new())
+    }
+
+    /// 使用自定义的 `reqwest::blocking::Client`, 比如设置了代理或超时
+    pub fn with_client(
+        secret_id: impl Into<String>,
+        secret_key: impl Into<String>,
+        client: reqwest::blocking::Client,
+    ) -> Self {
+        Self::with_transport(
+            secret_id,
+            secret_key,
+            crate::transport::ReqwestTransport::new(client),
+        )
+    }
+}
+
+impl<T: Transport> Client<T> {
+    /// 使用自定义的 [`Transport`] 发送请求
+    pub fn with_transport(
+        secret_id: impl Into<String>,
+        secret_key: impl Into<String>,
+        transport: T,
+    ) -> Self {
+        Self::with_provider(Credential::new(secret_id, secret_key), transport)
+    }
+
+    /// 密钥来自 [`CredentialProvider`], 比如 [`ChainProvider`](crate::credential::ChainProvider), 每次请求时获取
+    pub fn with_provider(provider: impl CredentialProvider + 'static, transport: T) -> Self {
+        Self {
+            config: Config::new(provider),
+            transport,
+        }
+    }
+
+    /// 请求发送到 `endpoint`, 比如国际站 [`DNSPOD_INTL_URL`](crate::consts::DNSPOD_INTL_URL)、代理或本地的测试服务器,
+    /// 签名中的 `host` 也取自 `endpoint`. 默认为各个请求的 [`ExtractCommonParams::url`]
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.config.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// 使用 `method` 发送所有请求, 默认为各个请求的 [`ExtractCommonParams::method`]
+    pub fn with_method(mut self, method: Method) -> Self {
+        self.config.method = Some(method);
+        self
+    }
+
+    /// 服务器返回的错误信息 (`X-TC-Language`) 和 [`Error::Api`](crate::Error::Api) 中错误码描述的语言, 默认为英文
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.config.language = language;
+        self
+    }
+
+    /// 请求失败后的重试策略, 默认不重试
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.config.retry = retry;
+        self
+    }
+
+    /// 发送前按 [`RateLimiter`] 等待
+    ///
+    /// 限速不会按 SecretId 自动共享: 同一个账号的多个 Client 需要传入同一个 RateLimiter,
+    /// 比如都使用 [`RateLimiter::shared`]`(secret_id)`, 否则各自计数, 合计可能超出接口频率限制
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.config.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// 签名使用的时间, 默认为系统时间
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.config.clock = Arc::new(clock);
+        self
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// 只签名不发送, 比如用 [`HttpRequest::to_curl`] 生成调试用的命令
+    pub fn sign(&self, request: &impl ExtractCommonParams) -> Result<HttpRequest> {
+        self.config.sign(request)
+    }
+
+    /// 服务器时间与本地时间的偏差, 服务器返回 `AuthFailure.SignatureExpire` 时根据其 `Date` 头部自动校正
+    pub fn clock_skew(&self) -> chrono::Duration {
+        self.config.skew()
+    }
+
+    /// 发送请求, 返回该请求对应的 [`ExtractOutput::Output`], 服务器返回 `Error` 时转换为 [`Error::Api`](crate::Error::Api)
+    pub fn execute<R: ExtractOutput>(&self, request: R) -> Result<R::Output> {
+        let res: Response<R::Output> = self.execute_as(request)?;
+        Ok(res.Response)
+    }
+
+    /// 把完整的返回结果(包括最外层的 `Response`)解析为任意类型, 比如 `serde_json::Value`
+    ///
+    /// 失败时按 [`RetryPolicy`] 重新签名并重试
+    pub fn execute_as<O: DeserializeOwned>(&self, request: impl ExtractCommonParams) -> Result<O> {
+        let mut attempt = 1;
+        loop {
+            match self.execute_once(&request) {
+                Err(e) if self.config.retry.should_retry(&request, &e, attempt) => {
+                    std::thread::sleep(self.config.retry.delay(attempt));
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// 自动翻页, 逐条返回 `request` 的列表中的条目. 从 `request` 的 Offset 开始, 每页数量为其 Limit
+    pub fn paginate<R: Paginate>(&self, request: R) -> Pages<'_, T, R> {
+        Pages::new(self, request)
+    }
+
+    /// 符合 `filter` 的所有域名
+    ///
+    /// ```
+    /// # use dnspod_lib::prelude::*;
+    /// # use dnspod_lib::transport::MemoryTransport;
+    /// let client = Client::with_transport("id", "key", MemoryTransport::new());
+    /// # client.transport().push_json(r#"{"Response": {"Error": {"Code": "ResourceNotFound.NoDataOfDomain", "Message": ""}, "RequestId": "1"}}"#);
+    /// for domain in client.domains(Default::default()) {
+    ///     println!("{}", domain?.Name);
+    /// }
+    /// # Ok::<(), dnspod_lib::Error>(())
+    /// ```
+    pub fn domains(&self, filter: DescribeDomainList) -> Pages<'_, T, DescribeDomainList> {
+        self.paginate(filter)
+    }
+
+    /// `domain` 下符合 `filter` 的所有记录, `filter` 中的 `Domain` 会被替换为 `domain`
+    pub fn records(
+        &self,
+        domain: impl Into<String>,
+        filter: DescribeRecordList,
+    ) -> Pages<'_, T, DescribeRecordList> {
+        self.paginate(DescribeRecordList {
+            Domain: domain.into(),
+            ..filter
+        })
+    }
+
+    fn execute_once<O: DeserializeOwned>(&self, request: &impl ExtractCommonParams) -> Result<O> {
+        let res = self.send(request)?;
+        match super::parse_response(&res.body, self.config.language) {
+            // 本地时间不准, 按服务器时间重新签名
+            Err(e) if self.config.correct_skew(&e, &res) => {
+                let res = self.send(request)?;
+                super::parse_response(&res.body, self.config.language)
+            }
+            res => res,
+        }
+    }
+
+    fn send(&self, request: &impl ExtractCommonParams) -> Result<HttpResponse> {
+        let wait = self.config.throttle(request);
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+        let request = self.config.sign(request)?;
+        self.transport.send(request)
+    }
+}
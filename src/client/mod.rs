@@ -0,0 +1,692 @@
+//! 内置的 Client, 负责签名、发送请求并解析返回结果
+//!
+//! + 同步的 [`Client`], 开启 `blocking` feature 后默认使用 reqwest 发送请求
+//! + 异步的 [`AsyncClient`], 开启 `async` feature 后默认使用 reqwest 发送请求
+//!
+//! 也可以通过 `with_transport` 使用其它的 [`Transport`](crate::transport::Transport),
+//! 通过 `with_retry` 设置失败后的 [`RetryPolicy`], 通过 `with_rate_limiter` 设置发送前的 [`RateLimiter`]
+//!
+//! 列表请求 (实现了 [`Paginate`]) 可以通过 `paginate`、`domains` 和 `records` 自动翻页
+
+mod async_client;
+mod blocking;
+mod paginate;
+mod rate_limit;
+mod retry;
+mod sleep;
+
+pub use async_client::AsyncClient;
+pub use blocking::Client;
+pub use paginate::{Pages, Paginate};
+pub use rate_limit::{Quota, RateLimiter};
+pub use retry::RetryPolicy;
+#[cfg(feature = "tokio")]
+pub use sleep::TokioSleeper;
+pub use sleep::{SleepFuture, Sleeper, TimerSleeper};
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::de::DeserializeOwned;
+
+use crate::clock::{Clock, SystemClock};
+use crate::credential::{Credential, CredentialProvider};
+use crate::data_types::Language;
+use crate::error::{Error, Result};
+use crate::error_code::ErrorCode;
+use crate::response;
+use crate::transport::{HttpRequest, HttpResponse, Method};
+use crate::{ExtractCommonParams, SignOptions};
+
+/// [`Client`] 和 [`AsyncClient`] 共用的配置
+#[derive(Clone)]
+pub(crate) struct Config {
+    pub(crate) provider: Arc<dyn CredentialProvider>,
+    pub(crate) clock: Arc<dyn Clock>,
+    /// 服务器时间与本地时间的偏差, 单位为秒
+    pub(crate) skew: Arc<AtomicI64>,
+    /// 覆盖请求的 [`ExtractCommonParams::url`]
+    pub(crate) endpoint: Option<String>,
+    /// 覆盖请求的 [`ExtractCommonParams::method`]
+    pub(crate) method: Option<Method>,
+    /// 服务器返回的错误信息和本地错误码描述的语言
+    pub(crate) language: Language,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+}
+
+impl Config {
+    pub(crate) fn new(provider: impl CredentialProvider + 'static) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            clock: Arc::new(SystemClock),
+            skew: Default::default(),
+            endpoint: None,
+            method: None,
+            language: Default::default(),
+            retry: Default::default(),
+            rate_limiter: None,
+        }
+    }
+
+    pub(crate) fn skew(&self) -> Duration {
+        Duration::seconds(self.skew.load(Ordering::Relaxed))
+    }
+
+    /// 发送 `request` 前需要等待的时间, 没有设置 [`RateLimiter`] 时为 0
+    pub(crate) fn throttle(&self, request: &impl ExtractCommonParams) -> std::time::Duration {
+        self.rate_limiter
+            .as_ref()
+            .map(|limiter| limiter.acquire(request.action()))
+            .unwrap_or_default()
+    }
+
+    /// 获取密钥并按 `clock` 的时间(加上校正的偏差)签名, 生成待发送的 [`HttpRequest`]
+    pub(crate) fn sign(&self, request: &impl ExtractCommonParams) -> Result<HttpRequest> {
+        let datetime = self.clock.now() + self.skew();
+        let credential = self.provider.credential_at(datetime)?;
+        let url = self.endpoint.as_deref().unwrap_or(request.url());
+        let method = self.method.unwrap_or(request.method());
+        sign(request, method, url, &credential, datetime, self.language)
+    }
+
+    /// 服务器返回 `AuthFailure.SignatureExpire` 时, 根据返回的 `Date` 头部校正时间偏差
+    ///
+    /// 返回 `true` 表示已校正, 需要重新签名后再发送
+    pub(crate) fn correct_skew(&self, error: &Error, response: &HttpResponse) -> bool {
+        if error.code() != Some(&ErrorCode::AuthFailureSignatureExpire) {
+            return false;
+        }
+        let Some(date) = response
+            .header("Date")
+            .and_then(|date| DateTime::parse_from_rfc2822(date).ok())
+        else {
+            return false;
+        };
+
+        let skew = date.timestamp() - self.clock.now().timestamp();
+        self.skew.store(skew, Ordering::Relaxed);
+        true
+    }
+}
+
+/// 对请求签名, 生成以 `method` 发送到 `url` 的 [`HttpRequest`]
+///
+/// GET 请求的参数放在 url 的查询字符串中, 请求正文为空. 请求参数无法序列化时返回 [`Error::Serialize`]
+pub(crate) fn sign(
+    request: &impl ExtractCommonParams,
+    method: Method,
+    url: &str,
+    credential: &Credential,
+    datetime: DateTime<Utc>,
+    language: Language,
+) -> Result<HttpRequest> {
+    request.validate()?;
+    let options = SignOptions {
+        language,
+        method: Some(method),
+        url: Some(url),
+        datetime: Some(datetime),
+    };
+    let headers = request.signed_headers(credential, &options)?;
+    let (url, body) = match method {
+        Method::GET => (
+            format!("{}/?{}", url.trim_end_matches('/'), request.try_query()?),
+            vec![],
+        ),
+        Method::POST => (url.to_string(), request.try_body()?),
+    };
+    Ok(HttpRequest {
+        method,
+        url,
+        headers,
+        body,
+    })
+}
+
+/// 解析服务器返回的内容, `Response.Error` 存在时转换为 [`Error::Api`], 错误码描述使用 `language`
+pub(crate) fn parse_response<T: DeserializeOwned>(body: &[u8], language: Language) -> Result<T> {
+    let decode_error = |source| Error::Decode {
+        source,
+        body: String::from_utf8_lossy(body).into_owned(),
+    };
+
+    let value: serde_json::Value = serde_json::from_slice(body).map_err(decode_error)?;
+
+    if let Some(error) = value.pointer("/Response/Error") {
+        let response::Error { code, Message } =
+            serde_json::from_value(error.clone()).map_err(decode_error)?;
+        let request_id = value
+            .pointer("/Response/RequestId")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        return Err(Error::Api {
+            code,
+            message: Message,
+            request_id,
+            language,
+        });
+    }
+
+    serde_json::from_value(value).map_err(decode_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Response;
+
+    #[test]
+    fn parse_error_response() {
+        let body = br#"{
+            "Response": {
+                "Error": {
+                    "Code": "AuthFailure.SignatureFailure",
+                    "Message": "The provided credentials could not be validated."
+                },
+                "RequestId": "ed93f3cb-f35e-473f-b9f3-0d451b8b79c6"
+            }
+        }"#;
+
+        let err = parse_response::<Response>(body, Language::default()).unwrap_err();
+        assert_eq!(
+            err.request_id(),
+            Some("ed93f3cb-f35e-473f-b9f3-0d451b8b79c6")
+        );
+        assert_eq!(err.code().unwrap().code(), "AuthFailure.SignatureFailure");
+    }
+
+    #[test]
+    fn parse_ok_response() {
+        let body = br#"{
+            "Response": {
+                "RecordId": 162,
+                "RequestId": "ab4f1426-ea15-42ea-8183-dc1b44151166"
+            }
+        }"#;
+
+        let res = parse_response::<Response>(body, Language::default()).unwrap();
+        assert_eq!(res.Response.RecordId, Some(162));
+        assert_eq!(res.request_id(), "ab4f1426-ea15-42ea-8183-dc1b44151166");
+    }
+
+    #[test]
+    fn parse_typed_response() {
+        use crate::action::DescribeRecordList;
+        use crate::ExtractOutput;
+
+        let body = r#"{
+            "Response": {
+                "RecordCountInfo": {
+                    "SubdomainCount": 1,
+                    "TotalCount": 1,
+                    "ListCount": 1
+                },
+                "RecordList": [
+                    {
+                        "RecordId": 556507778,
+                        "Value": "f1g1ns1.dnspod.net.",
+                        "Status": "ENABLE",
+                        "UpdatedOn": "2021-03-28 11:27:09",
+                        "Name": "@",
+                        "Line": "默认",
+                        "LineId": "0",
+                        "Type": "NS",
+                        "Weight": null,
+                        "MonitorStatus": "",
+                        "Remark": "",
+                        "TTL": 86400,
+                        "MX": 0,
+                        "DefaultNS": true
+                    }
+                ],
+                "RequestId": "ab4f1426-ea15-42ea-8183-dc1b44151166"
+            }
+        }"#
+        .as_bytes();
+
+        let res: Response<<DescribeRecordList as ExtractOutput>::Output> =
+            parse_response(body, Language::default()).unwrap();
+        assert_eq!(res.Response.RecordCountInfo.TotalCount, 1);
+        assert_eq!(res.Response.RecordList[0].RecordId, 556507778);
+        assert_eq!(
+            res.Response.RequestId,
+            "ab4f1426-ea15-42ea-8183-dc1b44151166"
+        );
+    }
+
+    #[test]
+    fn parse_invalid_response() {
+        let err = parse_response::<Response>(b"<html>502 Bad Gateway</html>", Language::default())
+            .unwrap_err();
+        assert!(matches!(err, Error::Decode { ref body, .. } if body.contains("502")));
+    }
+
+    #[test]
+    fn client_with_memory_transport() {
+        use crate::action::CreateRecord;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        transport.push_json(r#"{"Response": {"RecordId": 162, "RequestId": "1"}}"#);
+
+        let client = Client::with_transport("id", "key", &transport);
+        let res = client
+            .execute(CreateRecord {
+                Domain: "example.com".into(),
+                SubDomain: "www".into(),
+                RecordType: Default::default(),
+                RecordLine: Default::default(),
+                Value: "1.1.1.1".into(),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(res.RecordId, 162);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::POST);
+        assert_eq!(requests[0].url, crate::consts::DNSPOD_URL);
+        assert_eq!(requests[0].headers["X-TC-Action"], "CreateRecord");
+        assert!(requests[0].headers["Authorization"].contains("Credential=id/"));
+    }
+
+    #[test]
+    fn client_with_language() {
+        use crate::action::DeleteRecord;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        transport.push_json(r#"{"Response": {"Error": {"Code": "InvalidParameter.RecordIdInvalid", "Message": "记录编号错误。"}, "RequestId": "1"}}"#);
+        transport.push_json(r#"{"Response": {"Error": {"Code": "InvalidParameter.RecordIdInvalid", "Message": "Invalid record ID."}, "RequestId": "2"}}"#);
+        let request = DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 1,
+        };
+
+        let client = Client::with_transport("id", "key", &transport).with_language(Language::ZhCN);
+        let err = client.execute(request.clone()).unwrap_err();
+        assert!(err.to_string().contains("(记录编号错误。)"));
+
+        let client = Client::with_transport("id", "key", &transport);
+        let err = client.execute(request).unwrap_err();
+        assert!(err.to_string().contains("(Incorrect record ID.)"));
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].headers["X-TC-Language"], "zh-CN");
+        assert_eq!(requests[1].headers["X-TC-Language"], "en-US");
+    }
+
+    #[test]
+    fn client_with_temporary_credential() {
+        use crate::action::DeleteRecord;
+        use crate::credential::Credential;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        transport.push_json(r#"{"Response": {"RequestId": "1"}}"#);
+
+        let credential = Credential::new("id", "key").with_token("token");
+        let client = Client::with_provider(credential, &transport);
+        client
+            .execute(DeleteRecord {
+                Domain: "example.com".into(),
+                RecordId: 162,
+            })
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].headers["X-TC-Token"], "token");
+        assert!(requests[0].headers["Authorization"]
+            .contains("SignedHeaders=content-type;host;x-tc-action;x-tc-token,"));
+    }
+
+    #[test]
+    fn client_with_fixed_clock() {
+        use chrono::TimeZone;
+
+        use crate::action::DeleteRecord;
+        use crate::clock::FixedClock;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        transport.push_json(r#"{"Response": {"RequestId": "1"}}"#);
+        transport.push_json(r#"{"Response": {"RequestId": "2"}}"#);
+
+        let clock = FixedClock(Utc.timestamp_opt(1551113065, 0).unwrap());
+        let client = Client::with_transport("id", "key", &transport).with_clock(clock);
+        let request = DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 162,
+        };
+        client.execute(request.clone()).unwrap();
+        client.execute(request).unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].headers["X-TC-Timestamp"], "1551113065");
+        assert!(requests[0].headers["Authorization"].contains("/2019-02-25/dnspod/"));
+        assert_eq!(
+            requests[0].headers["Authorization"],
+            requests[1].headers["Authorization"]
+        );
+    }
+
+    #[test]
+    fn correct_clock_skew() {
+        use chrono::TimeZone;
+
+        use crate::action::DeleteRecord;
+        use crate::clock::FixedClock;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        // 服务器时间比本地快 1 小时
+        transport.push_response(
+            HttpResponse::ok(
+                r#"{"Response": {"Error": {"Code": "AuthFailure.SignatureExpire", "Message": ""}, "RequestId": "1"}}"#,
+            )
+            .with_header("Date", "Mon, 25 Feb 2019 17:44:25 GMT"),
+        );
+        transport.push_json(r#"{"Response": {"RequestId": "2"}}"#);
+        transport.push_json(r#"{"Response": {"RequestId": "3"}}"#);
+
+        let clock = FixedClock(Utc.timestamp_opt(1551113065, 0).unwrap());
+        let client = Client::with_transport("id", "key", &transport).with_clock(clock);
+        let request = DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 162,
+        };
+        let res = client.execute(request.clone()).unwrap();
+        assert_eq!(res.RequestId, "2");
+        assert_eq!(client.clock_skew(), Duration::hours(1));
+
+        client.execute(request).unwrap();
+        let requests = transport.requests();
+        assert_eq!(requests[0].headers["X-TC-Timestamp"], "1551113065");
+        assert_eq!(requests[1].headers["X-TC-Timestamp"], "1551116665");
+        assert_eq!(requests[2].headers["X-TC-Timestamp"], "1551116665");
+    }
+
+    #[test]
+    fn signature_expire_without_date() {
+        use crate::action::DeleteRecord;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        transport.push_json(
+            r#"{"Response": {"Error": {"Code": "AuthFailure.SignatureExpire", "Message": ""}, "RequestId": "1"}}"#,
+        );
+
+        let client = Client::with_transport("id", "key", &transport);
+        let err = client
+            .execute(DeleteRecord {
+                Domain: "example.com".into(),
+                RecordId: 162,
+            })
+            .unwrap_err();
+        assert_eq!(err.code().unwrap().code(), "AuthFailure.SignatureExpire");
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[test]
+    fn client_with_endpoint() {
+        use chrono::TimeZone;
+
+        use crate::action::DeleteRecord;
+        use crate::clock::FixedClock;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        transport.push_json(r#"{"Response": {"RequestId": "1"}}"#);
+
+        let datetime = Utc.timestamp_opt(1551113065, 0).unwrap();
+        let client = Client::with_transport("id", "key", &transport)
+            .with_clock(FixedClock(datetime))
+            .with_endpoint("http://127.0.0.1:8080");
+        let request = DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 162,
+        };
+        client.execute(request.clone()).unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].url, "http://127.0.0.1:8080");
+        // host 不同, 签名也不同
+        let credential = Credential::new("id", "key");
+        let options = SignOptions {
+            datetime: Some(datetime),
+            ..Default::default()
+        };
+        assert_eq!(
+            requests[0].headers,
+            request
+                .signed_headers(
+                    &credential,
+                    &SignOptions {
+                        method: Some(Method::POST),
+                        url: Some("http://127.0.0.1:8080/"),
+                        ..options
+                    }
+                )
+                .unwrap()
+        );
+        assert_ne!(
+            requests[0].headers["Authorization"],
+            request.signed_headers(&credential, &options).unwrap()["Authorization"]
+        );
+    }
+
+    #[test]
+    fn client_with_get_method() {
+        use crate::action::DescribeRecordList;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        let client = Client::with_transport("id", "key", &transport).with_method(Method::GET);
+        let request = client
+            .sign(&DescribeRecordList {
+                Domain: "example.com".into(),
+                Subdomain: Some("www".into()),
+                Keyword: None,
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(
+            request.url,
+            "https://dnspod.tencentcloudapi.com/?Domain=example.com&Subdomain=www"
+        );
+        assert!(request.body.is_empty());
+        assert_eq!(
+            request.headers["Content-Type"],
+            "application/x-www-form-urlencoded"
+        );
+
+        let curl = request.to_curl();
+        assert!(curl.starts_with(
+            "curl -X GET 'https://dnspod.tencentcloudapi.com/?Domain=example.com&Subdomain=www'"
+        ));
+        assert!(curl.contains(" -H 'X-TC-Action: DescribeRecordList'"));
+    }
+
+    #[test]
+    fn async_client_with_memory_transport() {
+        use std::future::Future;
+        use std::task::{Context, Poll, Waker};
+
+        use crate::action::DeleteRecord;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        transport.push_json(
+            r#"{"Response": {"Error": {"Code": "InvalidParameter.RecordIdInvalid", "Message": ""}, "RequestId": "2"}}"#,
+        );
+
+        let client = AsyncClient::with_transport("id", "key", &transport);
+        let fut = std::pin::pin!(client.execute(DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 0,
+        }));
+        let Poll::Ready(res) = fut.poll(&mut Context::from_waker(Waker::noop())) else {
+            panic!("MemoryTransport should be ready");
+        };
+
+        let err = res.unwrap_err();
+        assert_eq!(err.request_id(), Some("2"));
+        assert_eq!(
+            err.code().unwrap().code(),
+            "InvalidParameter.RecordIdInvalid"
+        );
+    }
+
+    #[test]
+    fn client_with_retry() {
+        use std::sync::atomic::AtomicI64;
+        use std::time::Duration as StdDuration;
+
+        use chrono::TimeZone;
+
+        use crate::action::{CreateRecord, DeleteRecord};
+        use crate::clock::Clock;
+        use crate::transport::MemoryTransport;
+
+        /// 每次取时间都前进 1 秒
+        struct Ticking(AtomicI64);
+        impl Clock for Ticking {
+            fn now(&self) -> DateTime<Utc> {
+                let t = self.0.fetch_add(1, Ordering::Relaxed);
+                Utc.timestamp_opt(t, 0).unwrap()
+            }
+        }
+
+        let limit = r#"{"Response": {"Error": {"Code": "RequestLimitExceeded", "Message": ""}, "RequestId": "1"}}"#;
+        let transport = MemoryTransport::new();
+        transport.push_json(limit);
+        transport.push_json(limit);
+        transport.push_json(r#"{"Response": {"RequestId": "3"}}"#);
+
+        let client = Client::with_transport("id", "key", &transport)
+            .with_clock(Ticking(AtomicI64::new(1551113065)))
+            .with_retry(RetryPolicy::new(3).with_backoff(StdDuration::ZERO, StdDuration::ZERO));
+        let delete = DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 1,
+        };
+        assert_eq!(client.execute(delete.clone()).unwrap().RequestId, "3");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        let timestamps: Vec<_> = requests
+            .iter()
+            .map(|r| r.headers["X-TC-Timestamp"].as_str())
+            .collect();
+        assert_eq!(timestamps, ["1551113065", "1551113066", "1551113067"]);
+        assert_ne!(
+            requests[0].headers["Authorization"],
+            requests[1].headers["Authorization"]
+        );
+
+        // 超过最大次数
+        for _ in 0..3 {
+            transport.push_json(limit);
+        }
+        let err = client.execute(delete).unwrap_err();
+        assert_eq!(err.code(), Some(&ErrorCode::RequestLimitExceeded));
+        assert_eq!(transport.requests().len(), 6);
+
+        // 不是幂等的请求不重试
+        transport.push_json(limit);
+        transport.push_json(r#"{"Response": {"RecordId": 162, "RequestId": "8"}}"#);
+        let create = CreateRecord {
+            Domain: "example.com".into(),
+            SubDomain: "www".into(),
+            RecordType: Default::default(),
+            RecordLine: Default::default(),
+            Value: "1.1.1.1".into(),
+            ..Default::default()
+        };
+        assert!(client.execute(create.clone()).is_err());
+        assert_eq!(transport.requests().len(), 7);
+
+        let client = client.with_retry(
+            RetryPolicy::new(3)
+                .with_backoff(StdDuration::ZERO, StdDuration::ZERO)
+                .allow_non_idempotent(true),
+        );
+        transport.push_json(limit);
+        transport.push_json(r#"{"Response": {"RecordId": 162, "RequestId": "10"}}"#);
+        assert_eq!(client.execute(create).unwrap().RecordId, 162);
+    }
+
+    #[test]
+    fn async_client_with_retry() {
+        use std::future::Future;
+        use std::task::{Context, Poll, Waker};
+        use std::time::Duration as StdDuration;
+
+        use crate::action::DeleteRecord;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        transport.push_json(
+            r#"{"Response": {"Error": {"Code": "InternalError", "Message": ""}, "RequestId": "1"}}"#,
+        );
+        transport.push_json(r#"{"Response": {"RequestId": "2"}}"#);
+
+        // 通过 Sleeper 等待, 而不是阻塞或另起线程
+        let slept = Arc::new(std::sync::Mutex::new(vec![]));
+        let recorder = slept.clone();
+        let client = AsyncClient::with_transport("id", "key", &transport)
+            .with_retry(
+                RetryPolicy::new(2)
+                    .with_backoff(StdDuration::from_secs(1), StdDuration::from_secs(1))
+                    .with_jitter(false),
+            )
+            .with_sleeper(move |duration| {
+                recorder.lock().unwrap().push(duration);
+                std::future::ready(())
+            });
+        let fut = std::pin::pin!(client.execute(DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 1,
+        }));
+        let Poll::Ready(res) = fut.poll(&mut Context::from_waker(Waker::noop())) else {
+            panic!("MemoryTransport should be ready");
+        };
+        assert_eq!(res.unwrap().RequestId, "2");
+        assert_eq!(transport.requests().len(), 2);
+        assert_eq!(*slept.lock().unwrap(), [StdDuration::from_secs(1)]);
+    }
+
+    #[test]
+    fn client_with_rate_limiter() {
+        use std::time::{Duration as StdDuration, Instant};
+
+        use crate::action::DeleteRecord;
+        use crate::transport::MemoryTransport;
+
+        let transport = MemoryTransport::new();
+        for _ in 0..3 {
+            transport.push_json(r#"{"Response": {"RequestId": "1"}}"#);
+        }
+        let limiter = RateLimiter::new()
+            .with_action("DeleteRecord", Quota::new(2, StdDuration::from_millis(100)));
+        let client = Client::with_transport("id", "key", &transport).with_rate_limiter(limiter);
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            client
+                .execute(DeleteRecord {
+                    Domain: "example.com".into(),
+                    RecordId: 1,
+                })
+                .unwrap();
+        }
+        // 前两个请求不需要等待, 第三个等待 50ms
+        assert!(start.elapsed() >= StdDuration::from_millis(50));
+        assert_eq!(transport.requests().len(), 3);
+    }
+}
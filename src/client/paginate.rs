@@ -0,0 +1,278 @@
+//! 按 Offset/Limit 自动翻页
+
+use std::collections::VecDeque;
+
+use crate::action::{DescribeDomainList, DescribeRecordFilterList, DescribeRecordList};
+use crate::error::Result;
+use crate::error_code::ErrorCode;
+use crate::response::{DomainListItem, RecordListItem};
+use crate::transport::Transport;
+use crate::ExtractOutput;
+
+use super::Client;
+
+/// 可以按 Offset/Limit 翻页的列表请求
+pub trait Paginate: ExtractOutput + Clone {
+    /// 列表中的条目
+    type Item;
+
+    /// 请求中的起始偏移和每页数量, 数量为 0 时使用接口的默认值
+    fn range(&self) -> (u64, u64);
+    /// 设置本页的偏移和数量
+    fn set_range(&mut self, offset: u64, limit: u64);
+    /// 从返回结果中取出本页的条目, 以及符合条件的总数
+    fn items(output: Self::Output) -> (Vec<Self::Item>, u64);
+}
+
+impl Paginate for DescribeDomainList {
+    type Item = DomainListItem;
+
+    fn range(&self) -> (u64, u64) {
+        (self.Offset, if self.Limit == 0 { 3000 } else { self.Limit })
+    }
+
+    fn set_range(&mut self, offset: u64, limit: u64) {
+        self.Offset = offset;
+        self.Limit = limit;
+    }
+
+    fn items(output: Self::Output) -> (Vec<Self::Item>, u64) {
+        (output.DomainList, output.DomainCountInfo.DomainTotal)
+    }
+}
+
+impl Paginate for DescribeRecordList {
+    type Item = RecordListItem;
+
+    fn range(&self) -> (u64, u64) {
+        let limit = self.Limit.filter(|&limit| limit > 0).unwrap_or(100);
+        (self.Offset.unwrap_or(0), limit)
+    }
+
+    fn set_range(&mut self, offset: u64, limit: u64) {
+        self.Offset = Some(offset);
+        self.Limit = Some(limit);
+    }
+
+    fn items(output: Self::Output) -> (Vec<Self::Item>, u64) {
+        (output.RecordList, output.RecordCountInfo.TotalCount)
+    }
+}
+
+impl Paginate for DescribeRecordFilterList {
+    type Item = RecordListItem;
+
+    fn range(&self) -> (u64, u64) {
+        let limit = self.Limit.filter(|&limit| limit > 0).unwrap_or(100);
+        (self.Offset.unwrap_or(0), limit)
+    }
+
+    fn set_range(&mut self, offset: u64, limit: u64) {
+        self.Offset = Some(offset);
+        self.Limit = Some(limit);
+    }
+
+    fn items(output: Self::Output) -> (Vec<Self::Item>, u64) {
+        (output.RecordList, output.RecordCountInfo.TotalCount)
+    }
+}
+
+/// 翻页的状态, [`Pages`] 和 [`AsyncClient::paginate`](super::AsyncClient::paginate) 共用
+pub(crate) struct Pager<R: Paginate> {
+    request: R,
+    offset: u64,
+    limit: u64,
+    total: Option<u64>,
+    items: VecDeque<R::Item>,
+    done: bool,
+}
+
+impl<R: Paginate> Pager<R> {
+    pub(crate) fn new(request: R) -> Self {
+        let (offset, limit) = request.range();
+        Self {
+            request,
+            offset,
+            limit,
+            total: None,
+            items: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// 当前页还没有返回的条目
+    pub(crate) fn pop(&mut self) -> Option<R::Item> {
+        self.items.pop_front()
+    }
+
+    /// 下一页的请求, 已经取完时返回 None
+    pub(crate) fn next_request(&mut self) -> Option<R> {
+        if self.done || self.total.is_some_and(|total| self.offset >= total) {
+            return None;
+        }
+        let mut request = self.request.clone();
+        request.set_range(self.offset, self.limit);
+        Some(request)
+    }
+
+    /// 处理一页的返回结果. 出错后不再继续翻页
+    ///
+    /// 没有符合条件的域名或记录时服务器返回 `ResourceNotFound.NoDataOf*`, 当作空列表
+    pub(crate) fn push(&mut self, res: Result<R::Output>) -> Result<()> {
+        match res {
+            Ok(output) => {
+                let (items, total) = R::items(output);
+                self.done = items.is_empty();
+                self.offset += items.len() as u64;
+                self.total = Some(total);
+                self.items.extend(items);
+                Ok(())
+            }
+            Err(e) => {
+                self.done = true;
+                match e.code() {
+                    Some(ErrorCode::ResourceNotFoundNoDataOfDomain)
+                    | Some(ErrorCode::ResourceNotFoundNoDataOfRecord) => Ok(()),
+                    _ => Err(e),
+                }
+            }
+        }
+    }
+}
+
+/// 逐条返回列表中的条目, 按需请求下一页, 直到取完符合条件的总数
+///
+/// 由 [`Client::paginate`]、[`Client::domains`] 和 [`Client::records`] 创建. 请求出错时返回 `Err`, 之后结束
+pub struct Pages<'a, T, R: Paginate> {
+    client: &'a Client<T>,
+    pager: Pager<R>,
+}
+
+impl<'a, T, R: Paginate> Pages<'a, T, R> {
+    pub(crate) fn new(client: &'a Client<T>, request: R) -> Self {
+        Self {
+            client,
+            pager: Pager::new(request),
+        }
+    }
+}
+
+impl<T: Transport, R: Paginate> Iterator for Pages<'_, T, R> {
+    type Item = Result<R::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.pager.pop() {
+                return Some(Ok(item));
+            }
+            let request = self.pager.next_request()?;
+            if let Err(e) = self.pager.push(self.client.execute(request)) {
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::client::AsyncClient;
+    use crate::transport::MemoryTransport;
+
+    fn page(ids: std::ops::Range<u64>, total: u64) -> String {
+        let list: Vec<_> = ids
+            .map(|id| {
+                json!({
+                    "RecordId": id, "Value": "1.1.1.1", "Status": "ENABLE",
+                    "UpdatedOn": "2021-03-28 11:27:09", "Name": "www", "Line": "默认",
+                    "LineId": "0", "Type": "A", "MonitorStatus": "", "Remark": "", "TTL": 600,
+                })
+            })
+            .collect();
+        json!({"Response": {
+            "RecordCountInfo": {"SubdomainCount": 1, "TotalCount": total, "ListCount": list.len()},
+            "RecordList": list,
+            "RequestId": "1",
+        }})
+        .to_string()
+    }
+
+    fn error(code: &str) -> String {
+        json!({"Response": {"Error": {"Code": code, "Message": ""}, "RequestId": "1"}}).to_string()
+    }
+
+    fn filter() -> DescribeRecordList {
+        DescribeRecordList {
+            Limit: Some(2),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn records() {
+        let transport = MemoryTransport::new();
+        transport.push_json(page(0..2, 5));
+        transport.push_json(page(2..4, 5));
+        transport.push_json(page(4..5, 5));
+        let client = Client::with_transport("id", "key", &transport);
+
+        let ids: Vec<_> = client
+            .records("example.com", filter())
+            .map(|r| r.unwrap().RecordId)
+            .collect();
+        assert_eq!(ids, [0, 1, 2, 3, 4]);
+
+        let bodies: Vec<serde_json::Value> = transport
+            .requests()
+            .iter()
+            .map(|r| serde_json::from_slice(&r.body).unwrap())
+            .collect();
+        assert_eq!(bodies.len(), 3);
+        for (i, body) in bodies.iter().enumerate() {
+            assert_eq!(body["Domain"], "example.com");
+            assert_eq!(body["Offset"], i * 2);
+            assert_eq!(body["Limit"], 2);
+        }
+    }
+
+    #[test]
+    fn lazy_and_errors() {
+        let transport = MemoryTransport::new();
+        transport.push_json(page(0..2, 5));
+        transport.push_json(error("InternalError"));
+        let client = Client::with_transport("id", "key", &transport);
+
+        let mut records = client.records("example.com", filter());
+        assert!(records.next().unwrap().is_ok());
+        assert_eq!(transport.requests().len(), 1);
+        assert!(records.next().unwrap().is_ok());
+        let err = records.next().unwrap().unwrap_err();
+        assert_eq!(err.code(), Some(&ErrorCode::InternalError));
+        assert!(records.next().is_none());
+
+        // 没有符合条件的记录
+        transport.push_json(error("ResourceNotFound.NoDataOfRecord"));
+        assert_eq!(client.records("example.com", filter()).count(), 0);
+    }
+
+    #[test]
+    fn async_records() {
+        use futures_util::{FutureExt, StreamExt};
+
+        let transport = MemoryTransport::new();
+        transport.push_json(page(0..2, 3));
+        transport.push_json(page(2..3, 3));
+        let client = AsyncClient::with_transport("id", "key", &transport);
+
+        let records: Vec<_> = client
+            .records("example.com", filter())
+            .collect()
+            .now_or_never()
+            .expect("MemoryTransport should be ready");
+        let ids: Vec<_> = records.into_iter().map(|r| r.unwrap().RecordId).collect();
+        assert_eq!(ids, [0, 1, 2]);
+        assert_eq!(transport.requests().len(), 2);
+    }
+}
@@ -0,0 +1,312 @@
+//! 客户端限流, 避免触发 `RequestLimitExceeded`
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// 令牌桶的速率: `period` 内最多 `requests` 个请求, 允许一次性用完(突发)
+///
+/// DNSPod 的接口默认限制为每秒 20 次, 具体以各接口文档为准
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quota {
+    requests: u32,
+    period: Duration,
+}
+
+impl Quota {
+    pub fn new(requests: u32, period: Duration) -> Self {
+        Self {
+            requests: requests.max(1),
+            period,
+        }
+    }
+
+    /// 每秒最多 `requests` 个请求
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// 每产生一个令牌需要的时间
+    fn interval(&self) -> Duration {
+        self.period / self.requests
+    }
+}
+
+/// 按 GCRA 计算的令牌桶
+#[derive(Debug)]
+struct Bucket {
+    quota: Quota,
+    /// 已发送请求的理论到达时间, 每个请求把它推后一个 interval. 为 None 或不晚于当前时间表示桶是满的
+    tat: Option<Instant>,
+    /// 已预留但还没到发送时间的请求, 按时间排序. 令牌在发送时才从桶里取出, 等待期间不影响其它请求
+    pending: Vec<Instant>,
+}
+
+impl Bucket {
+    fn new(quota: Quota) -> Self {
+        Self {
+            quota,
+            tat: None,
+            pending: vec![],
+        }
+    }
+
+    /// 桶里最多 `requests` 个令牌, 即 tat 最多比发送时间晚这么久
+    fn tolerance(&self) -> Duration {
+        self.quota.period - self.quota.interval()
+    }
+
+    /// 在 `at` 发送时桶里是否有令牌, 有则取出一个
+    fn take(&self, tat: &mut Option<Instant>, at: Instant) -> bool {
+        if tat.is_some_and(|tat| tat > at + self.tolerance()) {
+            return false;
+        }
+        *tat = Some(tat.map_or(at, |tat| tat.max(at)) + self.quota.interval());
+        true
+    }
+
+    /// 把 `now` 之前的预留计入 tat
+    fn advance(&mut self, now: Instant) {
+        let due = self.pending.partition_point(|&at| at <= now);
+        let mut tat = self.tat;
+        for &at in &self.pending[..due] {
+            self.take(&mut tat, at);
+        }
+        self.tat = tat;
+        self.pending.drain(..due);
+    }
+
+    /// 不早于 `at`、且不影响已预留请求的最早发送时间
+    fn ready_at(&self, mut at: Instant) -> Instant {
+        'retry: loop {
+            // 按时间顺序依次取令牌, 相同时间时已预留的请求优先
+            let split = self.pending.partition_point(|&t| t <= at);
+            let mut tat = self.tat;
+            for &t in &self.pending[..split] {
+                self.take(&mut tat, t);
+            }
+            if !self.take(&mut tat, at) {
+                at = tat.unwrap() - self.tolerance();
+                continue 'retry;
+            }
+            for &t in &self.pending[split..] {
+                if !self.take(&mut tat, t) {
+                    // 会挤占之后的预留, 排在它后面
+                    at = t;
+                    continue 'retry;
+                }
+            }
+            return at;
+        }
+    }
+
+    fn reserve(&mut self, at: Instant) {
+        let index = self.pending.partition_point(|&t| t <= at);
+        self.pending.insert(index, at);
+    }
+}
+
+#[derive(Debug, Default)]
+struct Buckets {
+    global: Option<Bucket>,
+    actions: HashMap<String, Bucket>,
+}
+
+/// 令牌桶限流, 可以限制所有请求的总速率, 也可以单独限制某个 Action
+///
+/// clone 得到的 RateLimiter 共享同一组令牌桶, 可以在多个线程和 Client 之间共用.
+/// 同一个 SecretId 的配额是共享的, [`RateLimiter::shared`] 为每个 SecretId 返回同一个 RateLimiter
+///
+/// ```
+/// use dnspod_lib::client::{Quota, RateLimiter};
+///
+/// let limiter = RateLimiter::shared("your-secret-id")
+///     .with_global(Quota::per_second(20))
+///     .with_action("DescribeRecordList", Quota::per_second(10));
+/// assert!(limiter.acquire("DescribeRecordList").is_zero());
+/// ```
+#[derive(Clone, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let buckets = self.buckets.lock().unwrap();
+        f.debug_struct("RateLimiter")
+            .field("global", &buckets.global.as_ref().map(|b| b.quota))
+            .field(
+                "actions",
+                &buckets
+                    .actions
+                    .iter()
+                    .map(|(k, v)| (k, v.quota))
+                    .collect::<HashMap<_, _>>(),
+            )
+            .finish()
+    }
+}
+
+impl RateLimiter {
+    /// 不限速, 通过 [`RateLimiter::with_global`] 和 [`RateLimiter::with_action`] 添加限制
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 同一个 `secret_id` 返回共享令牌桶的 RateLimiter
+    pub fn shared(secret_id: &str) -> Self {
+        static REGISTRY: OnceLock<Mutex<HashMap<String, RateLimiter>>> = OnceLock::new();
+        REGISTRY
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .entry(secret_id.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// 限制所有请求的总速率
+    ///
+    /// 已经设置过时保留原来的令牌桶和速率, 不会重置已有的计数.
+    /// 比如同一个 SecretId 的多个 Client 都通过 [`RateLimiter::shared`] 设置时, 以第一次设置的为准
+    pub fn with_global(self, quota: Quota) -> Self {
+        self.buckets
+            .lock()
+            .unwrap()
+            .global
+            .get_or_insert_with(|| Bucket::new(quota));
+        self
+    }
+
+    /// 限制 `action` (如 `DescribeRecordList`) 的速率, 同时也受 [`RateLimiter::with_global`] 的限制
+    ///
+    /// 与 [`RateLimiter::with_global`] 相同, 已经设置过时保留原来的令牌桶和速率
+    pub fn with_action(self, action: impl Into<String>, quota: Quota) -> Self {
+        self.buckets
+            .lock()
+            .unwrap()
+            .actions
+            .entry(action.into())
+            .or_insert_with(|| Bucket::new(quota));
+        self
+    }
+
+    /// 为 `action` 取一个令牌, 返回发送前需要等待的时间.
+    /// 令牌已在发送时间预留, 等待结束后直接发送即可
+    pub fn acquire(&self, action: &str) -> Duration {
+        self.acquire_at(action, Instant::now())
+    }
+
+    fn acquire_at(&self, action: &str, now: Instant) -> Duration {
+        let mut buckets = self.buckets.lock().unwrap();
+        let Buckets { global, actions } = &mut *buckets;
+        let mut buckets: Vec<_> = global.iter_mut().chain(actions.get_mut(action)).collect();
+        for bucket in &mut buckets {
+            bucket.advance(now);
+        }
+        // 先找到所有桶都有令牌的最早时间, 再在这个时间从每个桶预留令牌
+        let mut at = now;
+        loop {
+            let ready = buckets.iter().map(|b| b.ready_at(at)).max().unwrap_or(at);
+            if ready == at {
+                break;
+            }
+            at = ready;
+        }
+        for bucket in &mut buckets {
+            bucket.reserve(at);
+        }
+        at - now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_bucket() {
+        let now = Instant::now();
+        let limiter = RateLimiter::new().with_global(Quota::per_second(4));
+        for _ in 0..4 {
+            assert_eq!(limiter.acquire_at("A", now), Duration::ZERO);
+        }
+        assert_eq!(limiter.acquire_at("A", now), Duration::from_millis(250));
+        assert_eq!(limiter.acquire_at("B", now), Duration::from_millis(500));
+
+        // 一秒后桶重新装满
+        let now = now + Duration::from_millis(1500);
+        for _ in 0..4 {
+            assert_eq!(limiter.acquire_at("A", now), Duration::ZERO);
+        }
+        assert_eq!(limiter.acquire_at("A", now), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn per_action() {
+        let now = Instant::now();
+        let limiter = RateLimiter::new()
+            .with_global(Quota::per_second(10))
+            .with_action("DescribeRecordList", Quota::new(1, Duration::from_secs(2)));
+        assert_eq!(
+            limiter.acquire_at("DescribeRecordList", now),
+            Duration::ZERO
+        );
+        assert_eq!(
+            limiter.acquire_at("DescribeRecordList", now),
+            Duration::from_secs(2)
+        );
+        assert_eq!(limiter.acquire_at("DeleteRecord", now), Duration::ZERO);
+
+        assert!(RateLimiter::new().acquire_at("A", now).is_zero());
+    }
+
+    #[test]
+    fn action_exhausted_keeps_global() {
+        let now = Instant::now();
+        let limiter = RateLimiter::new()
+            .with_global(Quota::per_second(2))
+            .with_action("CreateRecord", Quota::new(1, Duration::from_secs(10)));
+        assert!(limiter.acquire_at("CreateRecord", now).is_zero());
+        assert_eq!(
+            limiter.acquire_at("CreateRecord", now),
+            Duration::from_secs(10)
+        );
+        // 等待中的 CreateRecord 在发送时才占用全局令牌, 桶里还剩一个
+        assert!(limiter.acquire_at("DeleteRecord", now).is_zero());
+        assert_eq!(
+            limiter.acquire_at("DeleteRecord", now),
+            Duration::from_millis(500)
+        );
+
+        // 桶重新装满, 但其中一个令牌要留给 10s 时发送的 CreateRecord
+        let now = now + Duration::from_millis(9900);
+        assert!(limiter.acquire_at("DeleteRecord", now).is_zero());
+        assert_eq!(
+            limiter.acquire_at("DeleteRecord", now),
+            Duration::from_millis(500)
+        );
+    }
+
+    #[test]
+    fn shared_by_secret_id() {
+        let now = Instant::now();
+        let a = RateLimiter::shared("rate-limit-test").with_global(Quota::per_second(1));
+        let b = RateLimiter::shared("rate-limit-test");
+        let c = RateLimiter::shared("rate-limit-test-other").with_global(Quota::per_second(1));
+        assert!(a.acquire_at("A", now).is_zero());
+        assert_eq!(b.acquire_at("A", now), Duration::from_secs(1));
+        assert!(c.acquire_at("A", now).is_zero());
+
+        // 另一个 Client 设置不同的速率不会重置共享的令牌桶
+        let d = RateLimiter::shared("rate-limit-test").with_global(Quota::per_second(100));
+        assert_eq!(d.acquire_at("A", now), Duration::from_secs(2));
+        let e =
+            RateLimiter::shared("rate-limit-test-action").with_action("A", Quota::per_second(1));
+        let f =
+            RateLimiter::shared("rate-limit-test-action").with_action("A", Quota::per_second(100));
+        assert!(e.acquire_at("A", now).is_zero());
+        assert_eq!(f.acquire_at("A", now), Duration::from_secs(1));
+    }
+}
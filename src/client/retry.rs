@@ -0,0 +1,237 @@
+//! 请求失败后的重试策略
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::error_code::ErrorCode;
+use crate::ExtractCommonParams;
+
+/// 请求失败后的重试策略, 默认不重试
+///
+/// 每次重试都会重新获取密钥并按当前时间签名. 是否重试依次判断:
+///
+/// + 不是幂等的请求 ([`ExtractCommonParams::idempotent`], 如 `CreateRecord`) 不重试, 除非 [`RetryPolicy::allow_non_idempotent`]
+/// + [`RetryPolicy::never_retry_on`] 中的错误码不重试
+/// + [`RetryPolicy::retry_on`] 中的错误码, 或 [`ErrorCode::is_retryable`] 的错误码重试
+/// + 网络错误默认重试, 可以通过 [`RetryPolicy::retry_transport_errors`] 关闭
+///
+/// 第 n 次重试前等待 `base_delay * 2^(n-1)`, 不超过 `max_delay`. 开启 jitter 时随机等待其一半到全部
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use dnspod_lib::client::RetryPolicy;
+/// use dnspod_lib::error_code::ErrorCode;
+///
+/// let policy = RetryPolicy::new(5)
+///     .with_backoff(Duration::from_millis(200), Duration::from_secs(5))
+///     .retry_on(ErrorCode::FailedOperationUnknowError)
+///     .never_retry_on(ErrorCode::InternalError);
+/// assert_eq!(policy.max_attempts(), 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retry_codes: Vec<ErrorCode>,
+    no_retry_codes: Vec<ErrorCode>,
+    retry_transport_errors: bool,
+    allow_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl RetryPolicy {
+    /// 最多发送 `max_attempts` 次(包括第一次). 默认从 100ms 开始等待, 最长 10s, 开启 jitter
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retry_codes: vec![],
+            no_retry_codes: vec![],
+            retry_transport_errors: true,
+            allow_non_idempotent: false,
+        }
+    }
+
+    /// 不重试
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// 第一次重试前等待 `base_delay`, 之后每次翻倍, 最长 `max_delay`
+    pub fn with_backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// 随机化等待时间, 避免多个客户端同时重试
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// 遇到 `code` 时重试, 即使它不是 [`ErrorCode::is_retryable`]
+    pub fn retry_on(mut self, code: impl Into<ErrorCode>) -> Self {
+        self.retry_codes.push(code.into());
+        self
+    }
+
+    /// 遇到 `code` 时不重试, 优先于 [`RetryPolicy::retry_on`]
+    pub fn never_retry_on(mut self, code: impl Into<ErrorCode>) -> Self {
+        self.no_retry_codes.push(code.into());
+        self
+    }
+
+    /// 网络错误时是否重试, 默认为 true
+    pub fn retry_transport_errors(mut self, retry: bool) -> Self {
+        self.retry_transport_errors = retry;
+        self
+    }
+
+    /// 是否重试不是幂等的请求, 默认为 false. 比如 `CreateRecord` 重试可能会添加重复的记录
+    pub fn allow_non_idempotent(mut self, allow: bool) -> Self {
+        self.allow_non_idempotent = allow;
+        self
+    }
+
+    /// 第 `attempt` 次(从 1 开始)发送 `request` 失败后是否重试
+    pub fn should_retry(
+        &self,
+        request: &impl ExtractCommonParams,
+        error: &Error,
+        attempt: u32,
+    ) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        if !request.idempotent() && !self.allow_non_idempotent {
+            return false;
+        }
+        match error {
+            Error::Api { code, .. } => {
+                !self.no_retry_codes.contains(code)
+                    && (self.retry_codes.contains(code) || code.is_retryable())
+            }
+            Error::Transport(_) => self.retry_transport_errors,
+            _ => false,
+        }
+    }
+
+    /// 第 `attempt` 次(从 1 开始)失败后, 重试前等待的时间
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+        let half = delay / 2;
+        let nanos = (half.as_nanos().min(u64::MAX as u128) as u64).saturating_add(1);
+        half + Duration::from_nanos(random() % nanos)
+    }
+}
+
+/// 每个 `RandomState` 的种子都不同, 不需要依赖 rand
+fn random() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::{CreateRecord, DeleteRecord};
+
+    fn api_error(code: &str) -> Error {
+        Error::Api {
+            code: code.into(),
+            message: String::new(),
+            request_id: String::new(),
+            language: Default::default(),
+        }
+    }
+
+    fn delete() -> DeleteRecord {
+        DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 1,
+        }
+    }
+
+    #[test]
+    fn should_retry() {
+        let policy = RetryPolicy::new(3);
+        let limit = api_error("RequestLimitExceeded.UinLimitExceeded");
+        assert!(policy.should_retry(&delete(), &limit, 1));
+        assert!(policy.should_retry(&delete(), &limit, 2));
+        assert!(!policy.should_retry(&delete(), &limit, 3));
+        assert!(!RetryPolicy::default().should_retry(&delete(), &limit, 1));
+
+        assert!(!policy.should_retry(&delete(), &api_error("InvalidParameter.RecordIdInvalid"), 1));
+        assert!(policy.should_retry(&delete(), &Error::Transport("timeout".into()), 1));
+        assert!(!policy.clone().retry_transport_errors(false).should_retry(
+            &delete(),
+            &Error::Transport("timeout".into()),
+            1
+        ));
+        assert!(!policy.should_retry(&delete(), &Error::Credential(String::new()), 1));
+
+        let policy = policy
+            .retry_on("FailedOperation.UnknowError")
+            .never_retry_on(ErrorCode::InternalError);
+        assert!(policy.should_retry(&delete(), &api_error("FailedOperation.UnknowError"), 1));
+        assert!(!policy.should_retry(&delete(), &api_error("InternalError"), 1));
+        assert!(policy.should_retry(&delete(), &api_error("ServiceUnavailable"), 1));
+    }
+
+    #[test]
+    fn never_retry_non_idempotent() {
+        let create = CreateRecord {
+            Domain: "example.com".into(),
+            SubDomain: "www".into(),
+            RecordType: Default::default(),
+            RecordLine: Default::default(),
+            Value: "1.1.1.1".into(),
+            ..Default::default()
+        };
+        let error = api_error("InternalError");
+        let policy = RetryPolicy::new(3);
+        assert!(!policy.should_retry(&create, &error, 1));
+        assert!(policy
+            .allow_non_idempotent(true)
+            .should_retry(&create, &error, 1));
+    }
+
+    #[test]
+    fn backoff() {
+        let policy = RetryPolicy::new(10)
+            .with_backoff(Duration::from_millis(100), Duration::from_secs(1))
+            .with_jitter(false);
+        let delays: Vec<_> = (1..=6).map(|n| policy.delay(n).as_millis()).collect();
+        assert_eq!(delays, [100, 200, 400, 800, 1000, 1000]);
+        assert_eq!(policy.delay(100), Duration::from_secs(1));
+
+        let policy = policy.with_jitter(true);
+        for n in 1..=6 {
+            let delay = policy.delay(n);
+            let max = policy.clone().with_jitter(false).delay(n);
+            assert!(delay >= max / 2 && delay <= max, "{delay:?} {max:?}");
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! [`AsyncClient`](super::AsyncClient) 重试和限速时的异步等待
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, OnceLock, Weak};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+/// 等待 `duration` 的 future
+pub type SleepFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// 异步等待, 与所用的异步运行时配合
+///
+/// 开启 `tokio` feature (`async` feature 默认开启) 时默认为 [`TokioSleeper`], 否则为 [`TimerSleeper`].
+/// 也可以是 `Fn(Duration) -> impl Future`, 比如 async-std 的 `task::sleep`
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use dnspod_lib::client::{AsyncClient, TimerSleeper};
+/// use dnspod_lib::transport::MemoryTransport;
+///
+/// let client = AsyncClient::with_transport("id", "key", MemoryTransport::new())
+///     .with_sleeper(TimerSleeper)
+///     .with_sleeper(|duration: Duration| async move { /* 等待 duration */ });
+/// ```
+pub trait Sleeper: Send + Sync {
+    fn sleep(&self, duration: Duration) -> SleepFuture;
+}
+
+impl<F, Fut> Sleeper for F
+where
+    F: Fn(Duration) -> Fut + Send + Sync,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(self(duration))
+    }
+}
+
+/// 使用 `tokio::time::sleep`, 需要在开启了 time 的 tokio 运行时中使用
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleeper;
+
+#[cfg(feature = "tokio")]
+impl Sleeper for TokioSleeper {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// 不依赖异步运行时, 所有等待共用一个后台线程, 按到期时间依次唤醒
+///
+/// future 被 drop 后不会再被唤醒
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimerSleeper;
+
+impl Sleeper for TimerSleeper {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(Sleep {
+            deadline: Instant::now() + duration,
+            state: None,
+        })
+    }
+}
+
+pub(crate) fn default_sleeper() -> Arc<dyn Sleeper> {
+    #[cfg(feature = "tokio")]
+    return Arc::new(TokioSleeper);
+    #[cfg(not(feature = "tokio"))]
+    return Arc::new(TimerSleeper);
+}
+
+/// 是否已到期, 以及到期时需要唤醒的 waker
+type State = Mutex<(bool, Option<Waker>)>;
+
+struct Sleep {
+    deadline: Instant,
+    state: Option<Arc<State>>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        match &self.state {
+            Some(state) => {
+                let mut state = state.lock().unwrap();
+                if state.0 {
+                    return Poll::Ready(());
+                }
+                state.1 = Some(cx.waker().clone());
+            }
+            None => {
+                let state = Arc::new(Mutex::new((false, Some(cx.waker().clone()))));
+                timer().add(self.deadline, Arc::downgrade(&state));
+                self.state = Some(state);
+            }
+        }
+        Poll::Pending
+    }
+}
+
+/// 按到期时间排序的等待, 最早到期的在堆顶
+struct Entry {
+    deadline: Instant,
+    state: Weak<State>,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.deadline == other.deadline
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.deadline.cmp(&self.deadline)
+    }
+}
+
+#[derive(Default)]
+struct Timer {
+    heap: Mutex<BinaryHeap<Entry>>,
+    changed: Condvar,
+}
+
+fn timer() -> &'static Timer {
+    static TIMER: OnceLock<&'static Timer> = OnceLock::new();
+    TIMER.get_or_init(|| {
+        let timer: &'static Timer = Box::leak(Box::default());
+        std::thread::Builder::new()
+            .name("dnspod-timer".into())
+            .spawn(|| timer.run())
+            .expect("failed to spawn timer thread");
+        timer
+    })
+}
+
+impl Timer {
+    fn add(&self, deadline: Instant, state: Weak<State>) {
+        self.heap.lock().unwrap().push(Entry { deadline, state });
+        self.changed.notify_one();
+    }
+
+    fn run(&self) {
+        let mut heap = self.heap.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            while heap.peek().is_some_and(|entry| entry.deadline <= now) {
+                let entry = heap.pop().unwrap();
+                // future 已被 drop 时跳过
+                if let Some(state) = entry.state.upgrade() {
+                    let mut state = state.lock().unwrap();
+                    state.0 = true;
+                    if let Some(waker) = state.1.take() {
+                        waker.wake();
+                    }
+                }
+            }
+            heap = match heap.peek() {
+                Some(entry) => {
+                    let timeout = entry.deadline.saturating_duration_since(now);
+                    self.changed.wait_timeout(heap, timeout).unwrap().0
+                }
+                None => self.changed.wait(heap).unwrap(),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::task::Wake;
+
+    use super::*;
+
+    struct Notify(Mutex<mpsc::Sender<()>>);
+
+    impl Wake for Notify {
+        fn wake(self: Arc<Self>) {
+            let _ = self.0.lock().unwrap().send(());
+        }
+    }
+
+    #[test]
+    fn timer_wakes_up() {
+        let (tx, rx) = mpsc::channel();
+        let waker = Waker::from(Arc::new(Notify(Mutex::new(tx))));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut long = TimerSleeper.sleep(Duration::from_secs(60));
+        let mut short = TimerSleeper.sleep(Duration::from_millis(10));
+        assert!(long.as_mut().poll(&mut cx).is_pending());
+        assert!(short.as_mut().poll(&mut cx).is_pending());
+        // 先到期的先唤醒, 不需要等待更晚的
+        rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(short.as_mut().poll(&mut cx).is_ready());
+        assert!(long.as_mut().poll(&mut cx).is_pending());
+        drop(long);
+
+        assert!(TimerSleeper
+            .sleep(Duration::ZERO)
+            .as_mut()
+            .poll(&mut cx)
+            .is_ready());
+    }
+
+    #[test]
+    fn closure_sleeper() {
+        let sleeper = |_: Duration| std::future::ready(());
+        let mut fut = sleeper.sleep(Duration::from_secs(60));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(fut.as_mut().poll(&mut cx).is_ready());
+    }
+}
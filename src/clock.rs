@@ -0,0 +1,52 @@
+//! 签名使用的时间
+//!
+//! `X-TC-Timestamp` 与服务器时间相差超过 5 分钟时请求会失败, 测试或复现签名时可以使用 [`FixedClock`]
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+/// 提供当前时间
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// 系统时间
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// 固定的时间, 用于测试
+///
+/// ```
+/// use dnspod_lib::chrono::{TimeZone, Utc};
+/// use dnspod_lib::clock::{Clock, FixedClock};
+///
+/// let clock = FixedClock(Utc.timestamp_opt(1551113065, 0).unwrap());
+/// assert_eq!(clock.now().timestamp(), 1551113065);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl<T: Clock + ?Sized> Clock for Box<T> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+impl<T: Clock + ?Sized> Clock for Arc<T> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
@@ -1,5 +1,9 @@
 pub const DNSPOD_DOMAIN_NAME: &str = "dnspod.tencentcloudapi.com";
 pub const DNSPOD_URL: &str = "https://dnspod.tencentcloudapi.com";
+/// 国际站
+pub const DNSPOD_INTL_URL: &str = "https://dnspod.intl.tencentcloudapi.com";
 pub const SERVICE: &str = "dnspod";
 pub const TERMINATOR: &str = "tc3_request";
 pub const ALGORITHM: &str = "TC3-HMAC-SHA256";
+pub const STS_URL: &str = "https://sts.tencentcloudapi.com";
+pub const STS_SERVICE: &str = "sts";
@@ -0,0 +1,143 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::{Credential, CredentialProvider};
+use crate::error::Result;
+
+/// 缓存 provider 返回的密钥, 临时密钥在过期前 `refresh_before` (默认 5 分钟) 重新获取
+///
+/// 没有过期时间的长期密钥会一直缓存; 刷新失败时, 如果缓存的密钥还没有真正过期, 继续使用缓存
+///
+/// ```
+/// use dnspod_lib::credential::*;
+///
+/// let provider = CachingProvider::new(CommandProvider::new("get-role-credential"));
+/// ```
+pub struct CachingProvider<P> {
+    provider: P,
+    refresh_before: Duration,
+    cached: Mutex<Option<Credential>>,
+}
+
+impl<P: CredentialProvider> CachingProvider<P> {
+    pub fn new(provider: P) -> Self {
+        Self {
+            provider,
+            refresh_before: Duration::minutes(5),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// 提前多久刷新
+    pub fn refresh_before(mut self, duration: Duration) -> Self {
+        self.refresh_before = duration;
+        self
+    }
+
+    pub(super) fn provider_mut(&mut self) -> &mut P {
+        &mut self.provider
+    }
+}
+
+impl<P: CredentialProvider> CredentialProvider for CachingProvider<P> {
+    fn credential(&self) -> Result<Credential> {
+        self.credential_at(Utc::now())
+    }
+
+    fn credential_at(&self, now: DateTime<Utc>) -> Result<Credential> {
+        let mut cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+        match cached.as_ref() {
+            Some(credential) if !credential.expires_within_at(now, self.refresh_before) => {
+                return Ok(credential.clone())
+            }
+            _ => {}
+        }
+
+        match self.provider.credential_at(now) {
+            Ok(credential) => {
+                *cached = Some(credential.clone());
+                Ok(credential)
+            }
+            Err(e) => match cached.as_ref() {
+                Some(credential) if !credential.is_expired_at(now) => Ok(credential.clone()),
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::error::Error;
+
+    struct Counter {
+        count: AtomicUsize,
+        lifetime: Option<Duration>,
+    }
+
+    impl CredentialProvider for Counter {
+        fn credential(&self) -> Result<Credential> {
+            let n = self.count.fetch_add(1, Ordering::SeqCst);
+            if n >= 2 {
+                return Err(Error::Credential("failed".into()));
+            }
+            let mut credential = Credential::new(format!("id{n}"), "key").with_token("token");
+            credential.expiration = self.lifetime.map(|lifetime| Utc::now() + lifetime);
+            Ok(credential)
+        }
+    }
+
+    #[test]
+    fn refresh_expiring_credential() {
+        let provider = CachingProvider::new(Counter {
+            count: AtomicUsize::new(0),
+            lifetime: Some(Duration::minutes(3)),
+        });
+        // 3 分钟后过期, 每次都需要刷新
+        assert_eq!(provider.credential().unwrap().secret_id, "id0");
+        assert_eq!(provider.credential().unwrap().secret_id, "id1");
+        // 刷新失败, 缓存的还没有过期
+        assert_eq!(provider.credential().unwrap().secret_id, "id1");
+
+        let provider = CachingProvider::new(Counter {
+            count: AtomicUsize::new(0),
+            lifetime: Some(Duration::minutes(3)),
+        })
+        .refresh_before(Duration::minutes(1));
+        assert_eq!(provider.credential().unwrap().secret_id, "id0");
+        assert_eq!(provider.credential().unwrap().secret_id, "id0");
+    }
+
+    #[test]
+    fn refresh_at_injected_time() {
+        let provider = CachingProvider::new(Counter {
+            count: AtomicUsize::new(0),
+            lifetime: Some(Duration::hours(1)),
+        });
+        let now = Utc::now();
+        assert_eq!(provider.credential_at(now).unwrap().secret_id, "id0");
+        assert_eq!(provider.credential_at(now).unwrap().secret_id, "id0");
+        // 按传入的时间判断过期, 而不是系统时间
+        let later = now + Duration::hours(2);
+        assert_eq!(provider.credential_at(later).unwrap().secret_id, "id1");
+        // 刷新失败, 缓存的在 `later` 已经过期
+        assert!(provider.credential_at(later).is_err());
+        assert_eq!(provider.credential_at(now).unwrap().secret_id, "id1");
+    }
+
+    #[test]
+    fn cache_long_term_credential() {
+        let provider = CachingProvider::new(Counter {
+            count: AtomicUsize::new(0),
+            lifetime: None,
+        });
+        assert_eq!(provider.credential().unwrap().secret_id, "id0");
+        assert_eq!(provider.credential().unwrap().secret_id, "id0");
+    }
+}
@@ -0,0 +1,100 @@
+use std::process::Command;
+
+use chrono::{TimeZone, Utc};
+use serde::Deserialize;
+
+use super::{Credential, CredentialProvider};
+use crate::error::{Error, Result};
+
+/// 执行外部命令获取密钥, 比如从密码管理器中读取
+///
+/// 命令需要在标准输出中打印如下格式的 json:
+///
+/// ```json
+/// {"SecretId": "AKIDz8krbsJ5yKBZQpn74WFkmLPx3*******", "SecretKey": "Gu5t9xGARNpq86cd98joQYCN3*******"}
+/// ```
+///
+/// 临时密钥可以额外输出 `Token` 和 `ExpiredTime` (Unix 时间戳), 配合 [`CachingProvider`](super::CachingProvider) 在过期前重新执行命令
+#[derive(Debug, Clone)]
+pub struct CommandProvider {
+    program: String,
+    args: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct CommandOutput {
+    SecretId: String,
+    SecretKey: String,
+    #[serde(default)]
+    Token: Option<String>,
+    #[serde(default)]
+    ExpiredTime: Option<i64>,
+}
+
+impl CommandProvider {
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: vec![],
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+}
+
+impl CredentialProvider for CommandProvider {
+    fn credential(&self) -> Result<Credential> {
+        let output = Command::new(&self.program)
+            .args(&self.args)
+            .output()
+            .map_err(|e| Error::Credential(format!("`{}`: {e}", self.program)))?;
+
+        if !output.status.success() {
+            return Err(Error::Credential(format!(
+                "`{}` exited with {}: {}",
+                self.program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let CommandOutput {
+            SecretId,
+            SecretKey,
+            Token,
+            ExpiredTime,
+        } = serde_json::from_slice(&output.stdout)
+            .map_err(|e| Error::Credential(format!("`{}`: invalid output: {e}", self.program)))?;
+
+        let mut credential = Credential::new(SecretId, SecretKey);
+        credential.token = Token;
+        credential.expiration = ExpiredTime.and_then(|t| Utc.timestamp_opt(t, 0).single());
+        Ok(credential)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_provider() {
+        let provider =
+            CommandProvider::new("echo").arg(r#"{"SecretId": "id", "SecretKey": "key"}"#);
+        assert_eq!(provider.credential().unwrap(), Credential::new("id", "key"));
+
+        let provider = CommandProvider::new("echo").arg(
+            r#"{"SecretId": "id", "SecretKey": "key", "Token": "token", "ExpiredTime": 1700000000}"#,
+        );
+        let credential = provider.credential().unwrap();
+        assert_eq!(credential.token.as_deref(), Some("token"));
+        assert_eq!(credential.expiration.unwrap().timestamp(), 1700000000);
+
+        let provider = CommandProvider::new("false");
+        assert!(provider.credential().is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use std::env;
+
+use super::{Credential, CredentialProvider};
+use crate::error::{Error, Result};
+
+/// 从环境变量读取密钥
+///
+/// 默认依次尝试 `DNSPOD_SECRET_ID`/`DNSPOD_SECRET_KEY` 和 `TENCENTCLOUD_SECRET_ID`/`TENCENTCLOUD_SECRET_KEY`,
+/// 临时密钥的 Token 从 `TENCENTCLOUD_SESSION_TOKEN` 读取
+#[derive(Debug, Clone)]
+pub struct EnvProvider {
+    vars: Vec<(String, String)>,
+    token_var: Option<String>,
+}
+
+impl Default for EnvProvider {
+    fn default() -> Self {
+        Self::new("DNSPOD_SECRET_ID", "DNSPOD_SECRET_KEY")
+            .or("TENCENTCLOUD_SECRET_ID", "TENCENTCLOUD_SECRET_KEY")
+            .with_token_var("TENCENTCLOUD_SESSION_TOKEN")
+    }
+}
+
+impl EnvProvider {
+    pub fn new(secret_id_var: impl Into<String>, secret_key_var: impl Into<String>) -> Self {
+        Self {
+            vars: vec![(secret_id_var.into(), secret_key_var.into())],
+            token_var: None,
+        }
+    }
+
+    /// 临时密钥的 Token 所在的环境变量
+    pub fn with_token_var(mut self, token_var: impl Into<String>) -> Self {
+        self.token_var = Some(token_var.into());
+        self
+    }
+
+    /// 前面的环境变量不存在时, 再尝试这一组
+    pub fn or(
+        mut self,
+        secret_id_var: impl Into<String>,
+        secret_key_var: impl Into<String>,
+    ) -> Self {
+        self.vars
+            .push((secret_id_var.into(), secret_key_var.into()));
+        self
+    }
+}
+
+impl CredentialProvider for EnvProvider {
+    fn credential(&self) -> Result<Credential> {
+        for (id_var, key_var) in &self.vars {
+            if let (Ok(secret_id), Ok(secret_key)) = (env::var(id_var), env::var(key_var)) {
+                if !secret_id.is_empty() && !secret_key.is_empty() {
+                    let mut credential = Credential::new(secret_id, secret_key);
+                    credential.token = self
+                        .token_var
+                        .as_ref()
+                        .and_then(|var| env::var(var).ok())
+                        .filter(|token| !token.is_empty());
+                    return Ok(credential);
+                }
+            }
+        }
+
+        let vars: Vec<_> = self
+            .vars
+            .iter()
+            .map(|(id, key)| format!("{id}/{key}"))
+            .collect();
+        Err(Error::Credential(format!(
+            "environment variables not set: {}",
+            vars.join(", ")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_provider() {
+        env::set_var("DNSPOD_LIB_TEST_ENV_ID", "id");
+        env::set_var("DNSPOD_LIB_TEST_ENV_KEY", "key");
+
+        let provider =
+            EnvProvider::new("DNSPOD_LIB_TEST_NOT_SET_ID", "DNSPOD_LIB_TEST_NOT_SET_KEY")
+                .or("DNSPOD_LIB_TEST_ENV_ID", "DNSPOD_LIB_TEST_ENV_KEY");
+        assert_eq!(provider.credential().unwrap(), Credential::new("id", "key"));
+
+        let provider =
+            EnvProvider::new("DNSPOD_LIB_TEST_NOT_SET_ID", "DNSPOD_LIB_TEST_NOT_SET_KEY");
+        assert!(provider.credential().is_err());
+
+        env::set_var("DNSPOD_LIB_TEST_ENV_TOKEN", "token");
+        let provider = EnvProvider::new("DNSPOD_LIB_TEST_ENV_ID", "DNSPOD_LIB_TEST_ENV_KEY")
+            .with_token_var("DNSPOD_LIB_TEST_ENV_TOKEN");
+        assert_eq!(
+            provider.credential().unwrap(),
+            Credential::new("id", "key").with_token("token")
+        );
+    }
+}
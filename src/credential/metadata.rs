@@ -0,0 +1,199 @@
+use chrono::{Duration, TimeZone, Utc};
+use serde::Deserialize;
+
+use super::{CachingProvider, Credential, CredentialProvider};
+use crate::error::{Error, Result};
+use crate::transport::{HttpRequest, Method, Transport};
+
+/// CVM 实例元数据服务中, 角色临时密钥的地址
+pub const METADATA_URL: &str =
+    "http://metadata.tencentyun.com/latest/meta-data/cam/security-credentials/";
+
+/// 从 CVM 实例元数据服务读取实例绑定的 CAM 角色的临时密钥
+/// <https://cloud.tencent.com/document/product/213/47668>
+///
+/// 未指定角色名时, 先从元数据服务读取实例绑定的角色. 临时密钥会缓存到 `ExpiredTime` 前 5 分钟
+///
+/// ```
+/// use dnspod_lib::credential::*;
+/// use dnspod_lib::transport::MemoryTransport;
+///
+/// let provider = MetadataProvider::new(MemoryTransport::new())
+///     .role("ddnspod")
+///     .base_url("http://127.0.0.1:8080/latest/meta-data/cam/security-credentials/");
+/// ```
+pub struct MetadataProvider<T> {
+    inner: CachingProvider<Metadata<T>>,
+}
+
+struct Metadata<T> {
+    transport: T,
+    base_url: String,
+    role: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct MetadataCredential {
+    TmpSecretId: String,
+    TmpSecretKey: String,
+    Token: String,
+    ExpiredTime: i64,
+    #[serde(default)]
+    Code: Option<String>,
+}
+
+impl<T: Transport + Send + Sync> MetadataProvider<T> {
+    pub fn new(transport: T) -> Self {
+        let inner = CachingProvider::new(Metadata {
+            transport,
+            base_url: METADATA_URL.into(),
+            role: None,
+        });
+        Self { inner }
+    }
+
+    /// 实例绑定的角色名, 不指定则从元数据服务读取
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.inner.provider_mut().role = Some(role.into());
+        self
+    }
+
+    /// 元数据服务地址, 默认为 [`METADATA_URL`]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        self.inner.provider_mut().base_url = base_url;
+        self
+    }
+
+    /// 提前多久刷新, 默认 5 分钟
+    pub fn refresh_before(mut self, duration: Duration) -> Self {
+        self.inner = self.inner.refresh_before(duration);
+        self
+    }
+}
+
+impl<T: Transport + Send + Sync> CredentialProvider for MetadataProvider<T> {
+    fn credential(&self) -> Result<Credential> {
+        self.inner.credential()
+    }
+}
+
+impl<T: Transport + Send + Sync> Metadata<T> {
+    fn get(&self, url: String) -> Result<Vec<u8>> {
+        let res = self
+            .transport
+            .send(HttpRequest {
+                method: Method::GET,
+                url: url.clone(),
+                headers: Default::default(),
+                body: vec![],
+            })
+            .map_err(|e| Error::Credential(format!("{url}: {e}")))?;
+
+        if res.status != 200 {
+            return Err(Error::Credential(format!(
+                "{url}: status {}: {}",
+                res.status,
+                String::from_utf8_lossy(&res.body).trim()
+            )));
+        }
+        Ok(res.body)
+    }
+
+    fn role(&self) -> Result<String> {
+        if let Some(ref role) = self.role {
+            return Ok(role.clone());
+        }
+        let body = self.get(self.base_url.clone())?;
+        // 每行一个角色名, 实例只能绑定一个角色
+        String::from_utf8_lossy(&body)
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(String::from)
+            .ok_or(Error::Credential(
+                "no CAM role bound to this instance".into(),
+            ))
+    }
+}
+
+impl<T: Transport + Send + Sync> CredentialProvider for Metadata<T> {
+    fn credential(&self) -> Result<Credential> {
+        let role = self.role()?;
+        let body = self.get(format!("{}{role}", self.base_url))?;
+        let res: MetadataCredential = serde_json::from_slice(&body)
+            .map_err(|e| Error::Credential(format!("invalid metadata credential: {e}")))?;
+
+        if let Some(code) = res.Code.filter(|code| code != "Success") {
+            return Err(Error::Credential(format!("metadata credential: {code}")));
+        }
+
+        let mut credential =
+            Credential::new(res.TmpSecretId, res.TmpSecretKey).with_token(res.Token);
+        credential.expiration = Utc.timestamp_opt(res.ExpiredTime, 0).single();
+        Ok(credential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::transport::{HttpResponse, MemoryTransport};
+
+    const BASE_URL: &str = "http://127.0.0.1:8080/meta-data/cam/security-credentials/";
+
+    fn metadata_server() -> Arc<MemoryTransport> {
+        Arc::new(MemoryTransport::with_handler(|req| {
+            assert_eq!(req.method, Method::GET);
+            let body = match req.url.strip_prefix(BASE_URL) {
+                Some("") => "ddnspod".to_string(),
+                Some("ddnspod") => serde_json::json!({
+                    "TmpSecretId": "tmp-id",
+                    "TmpSecretKey": "tmp-key",
+                    "ExpiredTime": (Utc::now() + Duration::hours(2)).timestamp(),
+                    "Expiration": "",
+                    "Token": "token",
+                    "Code": "Success"
+                })
+                .to_string(),
+                _ => {
+                    return Ok(HttpResponse {
+                        status: 404,
+                        body: b"Not Found".to_vec(),
+                        ..Default::default()
+                    })
+                }
+            };
+            Ok(HttpResponse::ok(body))
+        }))
+    }
+
+    #[test]
+    fn metadata_provider() {
+        let transport = metadata_server();
+        let provider = MetadataProvider::new(transport.clone()).base_url(BASE_URL);
+
+        let credential = provider.credential().unwrap();
+        assert_eq!(credential.secret_id, "tmp-id");
+        assert_eq!(credential.token.as_deref(), Some("token"));
+
+        // 使用缓存
+        assert_eq!(provider.credential().unwrap(), credential);
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn metadata_provider_unknown_role() {
+        let provider = MetadataProvider::new(metadata_server())
+            .base_url(BASE_URL.trim_end_matches('/'))
+            .role("unknown");
+        let err = provider.credential().unwrap_err().to_string();
+        assert!(err.contains("status 404"), "{err}");
+    }
+}
@@ -0,0 +1,231 @@
+//! 密钥 (SecretId/SecretKey) 的获取方式
+//!
+//! + [`Credential`] 直接指定
+//! + [`EnvProvider`] 环境变量 `DNSPOD_SECRET_ID`/`DNSPOD_SECRET_KEY` 或 `TENCENTCLOUD_SECRET_ID`/`TENCENTCLOUD_SECRET_KEY`
+//! + [`ProfileProvider`] 配置文件 `~/.tencentcloud/credentials`
+//! + [`CommandProvider`] 执行外部命令, 从输出中读取
+//! + [`ChainProvider`] 依次尝试多个 provider, 返回第一个成功的结果
+//! + [`CachingProvider`] 缓存临时密钥, 在过期前自动刷新
+//! + [`AssumeRoleProvider`] 通过 STS `AssumeRole` 获取角色的临时密钥
+//! + [`MetadataProvider`] 从 CVM 实例元数据服务读取实例角色的临时密钥
+
+mod cache;
+mod command;
+mod env;
+mod metadata;
+mod profile;
+mod sts;
+
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+pub use cache::CachingProvider;
+pub use command::CommandProvider;
+pub use env::EnvProvider;
+pub use metadata::{MetadataProvider, METADATA_URL};
+pub use profile::ProfileProvider;
+pub use sts::AssumeRoleProvider;
+
+use crate::error::{Error, Result};
+
+/// 云 API 密钥 <https://console.cloud.tencent.com/cam/capi>
+///
+/// 临时密钥 (STS/CAM 角色) 还需要 `token`, 请求时作为 `X-TC-Token` 发送
+#[derive(Clone, PartialEq, Eq)]
+pub struct Credential {
+    pub secret_id: String,
+    pub secret_key: String,
+    /// 临时密钥的 Token
+    pub token: Option<String>,
+    /// 临时密钥的过期时间, 长期密钥为 `None`
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+impl Credential {
+    pub fn new(secret_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            secret_id: secret_id.into(),
+            secret_key: secret_key.into(),
+            token: None,
+            expiration: None,
+        }
+    }
+
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn with_expiration(mut self, expiration: DateTime<Utc>) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// 是否会在 `duration` 之内过期
+    pub fn expires_within(&self, duration: Duration) -> bool {
+        self.expires_within_at(Utc::now(), duration)
+    }
+
+    /// 以 `now` 为当前时间, 是否会在 `duration` 之内过期
+    pub fn expires_within_at(&self, now: DateTime<Utc>, duration: Duration) -> bool {
+        self.expiration
+            .is_some_and(|expiration| expiration <= now + duration)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Utc::now())
+    }
+
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.expires_within_at(now, Duration::zero())
+    }
+}
+
+/// 不打印 SecretKey 和 Token
+impl fmt::Debug for Credential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credential")
+            .field("secret_id", &self.secret_id)
+            .field("secret_key", &"******")
+            .field("token", &self.token.as_ref().map(|_| "******"))
+            .field("expiration", &self.expiration)
+            .finish()
+    }
+}
+
+/// 获取密钥
+pub trait CredentialProvider: Send + Sync {
+    fn credential(&self) -> Result<Credential>;
+
+    /// 以 `now` 为当前时间获取密钥, 需要调用云 API 的 provider (如 [`AssumeRoleProvider`]) 用它签名.
+    /// Client 传入的是它的 [`Clock`](crate::clock::Clock) 加上校正后的时间偏差, 默认忽略 `now`
+    fn credential_at(&self, now: DateTime<Utc>) -> Result<Credential> {
+        let _ = now;
+        self.credential()
+    }
+}
+
+impl CredentialProvider for Credential {
+    fn credential(&self) -> Result<Credential> {
+        Ok(self.clone())
+    }
+}
+
+impl<T: CredentialProvider + ?Sized> CredentialProvider for Box<T> {
+    fn credential(&self) -> Result<Credential> {
+        (**self).credential()
+    }
+    fn credential_at(&self, now: DateTime<Utc>) -> Result<Credential> {
+        (**self).credential_at(now)
+    }
+}
+
+impl<T: CredentialProvider + ?Sized> CredentialProvider for Arc<T> {
+    fn credential(&self) -> Result<Credential> {
+        (**self).credential()
+    }
+    fn credential_at(&self, now: DateTime<Utc>) -> Result<Credential> {
+        (**self).credential_at(now)
+    }
+}
+
+/// 依次尝试多个 provider, 返回第一个成功的结果
+///
+/// ```
+/// use dnspod_lib::credential::*;
+///
+/// let provider = ChainProvider::new()
+///     .with(EnvProvider::default())
+///     .with(ProfileProvider::new("production"));
+/// ```
+#[derive(Default)]
+pub struct ChainProvider {
+    providers: Vec<Box<dyn CredentialProvider>>,
+}
+
+impl ChainProvider {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// 环境变量, 然后是 `~/.tencentcloud/credentials` 中的 profile
+    /// (`TENCENTCLOUD_PROFILE` 指定, 默认为 `default`)
+    pub fn default_chain() -> Self {
+        Self::new()
+            .with(EnvProvider::default())
+            .with(ProfileProvider::default())
+    }
+
+    pub fn with(mut self, provider: impl CredentialProvider + 'static) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+}
+
+impl CredentialProvider for ChainProvider {
+    fn credential(&self) -> Result<Credential> {
+        self.credential_at(Utc::now())
+    }
+
+    fn credential_at(&self, now: DateTime<Utc>) -> Result<Credential> {
+        let mut errors = vec![];
+        for provider in &self.providers {
+            match provider.credential_at(now) {
+                Ok(credential) => return Ok(credential),
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+        Err(Error::Credential(format!(
+            "no credential found in chain: [{}]",
+            errors.join("; ")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Failed;
+
+    impl CredentialProvider for Failed {
+        fn credential(&self) -> Result<Credential> {
+            Err(Error::Credential("failed".into()))
+        }
+    }
+
+    #[test]
+    fn chain() {
+        let chain = ChainProvider::new()
+            .with(Failed)
+            .with(Credential::new("id", "key"))
+            .with(Credential::new("id2", "key2"));
+        assert_eq!(chain.credential().unwrap(), Credential::new("id", "key"));
+
+        let chain = ChainProvider::new().with(Failed).with(Failed);
+        let err = chain.credential().unwrap_err().to_string();
+        assert!(err.contains("failed; credential error: failed"), "{err}");
+    }
+
+    #[test]
+    fn debug_hides_secret_key() {
+        let s = format!("{:?}", Credential::new("id", "key"));
+        assert!(!s.contains("key\""));
+
+        let s = format!("{:?}", Credential::new("id", "key").with_token("token"));
+        assert!(!s.contains("token\""));
+    }
+
+    #[test]
+    fn expiration() {
+        let credential = Credential::new("id", "key");
+        assert!(!credential.is_expired());
+
+        let credential = credential.with_expiration(Utc::now() + Duration::minutes(3));
+        assert!(!credential.is_expired());
+        assert!(credential.expires_within(Duration::minutes(5)));
+        assert!(!credential.expires_within(Duration::minutes(1)));
+    }
+}
@@ -0,0 +1,164 @@
+use std::path::PathBuf;
+
+use super::{Credential, CredentialProvider};
+use crate::error::{Error, Result};
+
+/// 从 `~/.tencentcloud/credentials` 读取密钥, 格式如下:
+///
+/// ```ini
+/// [default]
+/// secret_id = AKIDz8krbsJ5yKBZQpn74WFkmLPx3*******
+/// secret_key = Gu5t9xGARNpq86cd98joQYCN3*******
+///
+/// [production]
+/// secret_id = ...
+/// secret_key = ...
+/// # 临时密钥
+/// token = ...
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProfileProvider {
+    path: Option<PathBuf>,
+    profile: String,
+}
+
+/// 环境变量 `TENCENTCLOUD_PROFILE` 指定的 profile, 默认为 `default`
+impl Default for ProfileProvider {
+    fn default() -> Self {
+        let profile = std::env::var("TENCENTCLOUD_PROFILE").unwrap_or("default".into());
+        Self::new(profile)
+    }
+}
+
+impl ProfileProvider {
+    pub fn new(profile: impl Into<String>) -> Self {
+        Self {
+            path: None,
+            profile: profile.into(),
+        }
+    }
+
+    /// 使用其它位置的配置文件
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    fn path(&self) -> Result<PathBuf> {
+        if let Some(ref path) = self.path {
+            return Ok(path.clone());
+        }
+        std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(|home| {
+                PathBuf::from(home)
+                    .join(".tencentcloud")
+                    .join("credentials")
+            })
+            .ok_or(Error::Credential("can not find home directory".into()))
+    }
+}
+
+impl CredentialProvider for ProfileProvider {
+    fn credential(&self) -> Result<Credential> {
+        let path = self.path()?;
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| Error::Credential(format!("{}: {e}", path.display())))?;
+
+        let section = parse_section(&content, &self.profile).ok_or(Error::Credential(format!(
+            "profile [{}] not found in {}",
+            self.profile,
+            path.display()
+        )))?;
+
+        let get = |key: &str| {
+            section
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+                .ok_or(Error::Credential(format!(
+                    "`{key}` not found in profile [{}]",
+                    self.profile
+                )))
+        };
+
+        let mut credential = Credential::new(get("secret_id")?, get("secret_key")?);
+        credential.token = get("token").ok();
+        Ok(credential)
+    }
+}
+
+/// 解析 INI 中的某个 section, 返回其中所有的 key = value
+fn parse_section(content: &str, name: &str) -> Option<Vec<(String, String)>> {
+    let mut found = false;
+    let mut pairs = vec![];
+
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            if found {
+                break;
+            }
+            found = section.trim() == name;
+            continue;
+        }
+        if found {
+            if let Some((k, v)) = line.split_once('=') {
+                pairs.push((k.trim().to_string(), v.trim().to_string()));
+            }
+        }
+    }
+
+    found.then_some(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = r#"
+# comment
+[default]
+secret_id = default-id
+secret_key = default-key
+
+[production]
+secret_id=production-id
+; comment
+secret_key = production-key
+
+[temporary]
+secret_id = temporary-id
+secret_key = temporary-key
+token = temporary-token
+"#;
+
+    #[test]
+    fn profile_provider() {
+        let path = std::env::temp_dir().join("dnspod-lib-test-credentials");
+        std::fs::write(&path, CONTENT).unwrap();
+
+        let provider = ProfileProvider::new("production").with_path(&path);
+        assert_eq!(
+            provider.credential().unwrap(),
+            Credential::new("production-id", "production-key")
+        );
+
+        let provider = ProfileProvider::new("default").with_path(&path);
+        assert_eq!(
+            provider.credential().unwrap(),
+            Credential::new("default-id", "default-key")
+        );
+
+        let provider = ProfileProvider::new("temporary").with_path(&path);
+        assert_eq!(
+            provider.credential().unwrap(),
+            Credential::new("temporary-id", "temporary-key").with_token("temporary-token")
+        );
+
+        let provider = ProfileProvider::new("staging").with_path(&path);
+        assert!(provider.credential().is_err());
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{CachingProvider, Credential, CredentialProvider};
+use crate::client::parse_response;
+use crate::consts::{STS_SERVICE, STS_URL};
+use crate::data_types::{ContentType, Language, Region, Version};
+use crate::error::{Error, Result};
+use crate::header_params::HeaderParams;
+use crate::response::Response;
+use crate::signature::calculate_service_authorization;
+use crate::transport::{HttpRequest, Method, Transport};
+use crate::utils::url_host;
+
+/// 通过 STS `AssumeRole` 申请扮演角色, 获取该角色的临时密钥
+/// <https://cloud.tencent.com/document/api/1312/48197>
+///
+/// 调用 `AssumeRole` 使用的密钥来自 `source`, 获取到的临时密钥会缓存到过期前 5 分钟
+///
+/// ```
+/// use dnspod_lib::credential::*;
+/// use dnspod_lib::transport::MemoryTransport;
+///
+/// let provider = AssumeRoleProvider::new(
+///     EnvProvider::default(),
+///     "qcs::cam::uin/100000000001:roleName/dns-production",
+///     MemoryTransport::new(),
+/// )
+/// .session_name("ddnspod")
+/// .duration_seconds(3600);
+/// ```
+pub struct AssumeRoleProvider<T> {
+    inner: CachingProvider<AssumeRole<T>>,
+}
+
+struct AssumeRole<T> {
+    source: Arc<dyn CredentialProvider>,
+    transport: T,
+    endpoint: String,
+    region: Region,
+    params: AssumeRoleParams,
+}
+
+/// <https://cloud.tencent.com/document/api/1312/48197>
+#[derive(Debug, Clone, Serialize)]
+#[allow(non_snake_case)]
+struct AssumeRoleParams {
+    RoleArn: String,
+    RoleSessionName: String,
+    DurationSeconds: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    Policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ExternalId: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct AssumeRoleResponse {
+    Credentials: StsCredentials,
+    ExpiredTime: i64,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct StsCredentials {
+    Token: String,
+    TmpSecretId: String,
+    TmpSecretKey: String,
+}
+
+impl<T: Transport + Send + Sync> AssumeRoleProvider<T> {
+    /// `role_arn` 格式为 `qcs::cam::uin/{主账号 uin}:roleName/{角色名}`
+    pub fn new(
+        source: impl CredentialProvider + 'static,
+        role_arn: impl Into<String>,
+        transport: T,
+    ) -> Self {
+        let inner = CachingProvider::new(AssumeRole {
+            source: Arc::new(source),
+            transport,
+            endpoint: STS_URL.into(),
+            region: Region::default(),
+            params: AssumeRoleParams {
+                RoleArn: role_arn.into(),
+                RoleSessionName: "dnspod-lib".into(),
+                DurationSeconds: 7200,
+                Policy: None,
+                ExternalId: None,
+            },
+        });
+        Self { inner }
+    }
+
+    /// 临时会话名称, 默认为 `dnspod-lib`
+    pub fn session_name(mut self, name: impl Into<String>) -> Self {
+        self.inner.provider_mut().params.RoleSessionName = name.into();
+        self
+    }
+
+    /// 临时密钥的有效时长, 单位为秒, 默认 7200 秒, 最长 43200 秒
+    pub fn duration_seconds(mut self, seconds: u64) -> Self {
+        self.inner.provider_mut().params.DurationSeconds = seconds;
+        self
+    }
+
+    /// 策略描述, 进一步限制临时密钥的权限
+    pub fn policy(mut self, policy: impl Into<String>) -> Self {
+        self.inner.provider_mut().params.Policy = Some(policy.into());
+        self
+    }
+
+    /// 角色外部 ID
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.inner.provider_mut().params.ExternalId = Some(external_id.into());
+        self
+    }
+
+    /// 默认为 `ap-guangzhou`
+    pub fn region(mut self, region: Region) -> Self {
+        self.inner.provider_mut().region = region;
+        self
+    }
+
+    /// STS 接口地址, 默认为 `https://sts.tencentcloudapi.com`
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.inner.provider_mut().endpoint = endpoint.into();
+        self
+    }
+
+    /// 提前多久刷新, 默认 5 分钟
+    pub fn refresh_before(mut self, duration: Duration) -> Self {
+        self.inner = self.inner.refresh_before(duration);
+        self
+    }
+}
+
+impl<T: Transport + Send + Sync> CredentialProvider for AssumeRoleProvider<T> {
+    fn credential(&self) -> Result<Credential> {
+        self.inner.credential()
+    }
+
+    fn credential_at(&self, now: DateTime<Utc>) -> Result<Credential> {
+        self.inner.credential_at(now)
+    }
+}
+
+impl<T: Transport + Send + Sync> AssumeRole<T> {
+    /// 以 `datetime` 签名, 与服务器时间相差超过 5 分钟时 STS 会返回 `AuthFailure.SignatureExpire`
+    fn sign(&self, credential: &Credential, datetime: DateTime<Utc>) -> Result<HttpRequest> {
+        let body = serde_json::to_vec(&self.params).map_err(Error::Serialize)?;
+
+        let mut hp = HeaderParams {
+            action: "AssumeRole",
+            version: Version::Version2018_08_13,
+            region: Some(self.region.clone()),
+            datetime,
+            authorization: "".into(),
+            language: Language::EnUS,
+            content_type: ContentType::JSON,
+            token: credential.token.clone(),
+        };
+        hp.authorization = calculate_service_authorization(
+            STS_SERVICE,
+            url_host(&self.endpoint),
+            &body,
+            &hp,
+            &credential.secret_id,
+            &credential.secret_key,
+        );
+
+        Ok(HttpRequest {
+            method: Method::POST,
+            url: self.endpoint.clone(),
+            headers: HashMap::try_from(hp)?,
+            body,
+        })
+    }
+}
+
+impl<T: Transport + Send + Sync> CredentialProvider for AssumeRole<T> {
+    fn credential(&self) -> Result<Credential> {
+        self.credential_at(Utc::now())
+    }
+
+    fn credential_at(&self, now: DateTime<Utc>) -> Result<Credential> {
+        let source = self.source.credential_at(now)?;
+        let res = self.transport.send(self.sign(&source, now)?)?;
+        let Response::<AssumeRoleResponse> { Response: res } =
+            parse_response(&res.body, Language::default())?;
+
+        let mut credential =
+            Credential::new(res.Credentials.TmpSecretId, res.Credentials.TmpSecretKey)
+                .with_token(res.Credentials.Token);
+        credential.expiration = Utc.timestamp_opt(res.ExpiredTime, 0).single();
+        Ok(credential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{HttpResponse, MemoryTransport};
+
+    #[test]
+    fn assume_role() {
+        let transport = Arc::new(MemoryTransport::with_handler(|req| {
+            assert_eq!(req.url, "http://127.0.0.1:8080");
+            assert_eq!(req.headers["X-TC-Action"], "AssumeRole");
+            assert_eq!(req.headers["X-TC-Version"], "2018-08-13");
+            assert_eq!(req.headers["X-TC-Region"], "ap-shanghai");
+            assert!(req.headers["Authorization"].contains("Credential=id/"));
+            assert!(req.headers["Authorization"].contains("/sts/tc3_request"));
+
+            let params: serde_json::Value = serde_json::from_slice(&req.body).unwrap();
+            assert_eq!(params["RoleArn"], "qcs::cam::uin/1:roleName/dns");
+            assert_eq!(params["RoleSessionName"], "ci");
+
+            let body = serde_json::json!({
+                "Response": {
+                    "Credentials": {
+                        "Token": "token",
+                        "TmpSecretId": "tmp-id",
+                        "TmpSecretKey": "tmp-key"
+                    },
+                    "ExpiredTime": (Utc::now() + Duration::hours(2)).timestamp(),
+                    "Expiration": "",
+                    "RequestId": "1"
+                }
+            });
+            Ok(HttpResponse::ok(body.to_string()))
+        }));
+
+        let provider = AssumeRoleProvider::new(
+            Credential::new("id", "key"),
+            "qcs::cam::uin/1:roleName/dns",
+            transport.clone(),
+        )
+        .session_name("ci")
+        .region(Region::ApShanghai)
+        .endpoint("http://127.0.0.1:8080");
+
+        let credential = provider.credential().unwrap();
+        assert_eq!(credential.secret_id, "tmp-id");
+        assert_eq!(credential.token.as_deref(), Some("token"));
+        assert!(!credential.expires_within(Duration::hours(1)));
+
+        // 使用缓存
+        assert_eq!(provider.credential().unwrap(), credential);
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[test]
+    fn assume_role_with_client_clock() {
+        use crate::action::DeleteRecord;
+        use crate::client::Client;
+        use crate::clock::FixedClock;
+
+        let now = Utc.timestamp_opt(1551113065, 0).unwrap();
+        let sts = Arc::new(MemoryTransport::new());
+        sts.push_json(
+            serde_json::json!({
+                "Response": {
+                    "Credentials": {"Token": "token", "TmpSecretId": "tmp-id", "TmpSecretKey": "tmp-key"},
+                    "ExpiredTime": (Utc::now() + Duration::hours(2)).timestamp(),
+                    "RequestId": "1"
+                }
+            })
+            .to_string(),
+        );
+        let provider = AssumeRoleProvider::new(
+            Credential::new("id", "key"),
+            "qcs::cam::uin/1:roleName/dns",
+            sts.clone(),
+        );
+
+        // STS 请求与 DNSPod 请求使用同一个时间签名
+        let transport = MemoryTransport::new();
+        transport.push_json(r#"{"Response": {"RequestId": "2"}}"#);
+        let client = Client::with_provider(provider, &transport).with_clock(FixedClock(now));
+        client
+            .execute(DeleteRecord {
+                Domain: "example.com".into(),
+                RecordId: 162,
+            })
+            .unwrap();
+        assert_eq!(sts.requests()[0].headers["X-TC-Timestamp"], "1551113065");
+        assert_eq!(transport.requests()[0].headers["X-TC-Token"], "token");
+    }
+
+    #[test]
+    fn assume_role_error() {
+        let transport = MemoryTransport::new();
+        transport.push_json(
+            r#"{"Response": {"Error": {"Code": "AuthFailure.SecretIdNotFound", "Message": ""}, "RequestId": "1"}}"#,
+        );
+
+        let provider = AssumeRoleProvider::new(
+            Credential::new("id", "key"),
+            "qcs::cam::uin/1:roleName/dns",
+            transport,
+        );
+        let err = provider.credential().unwrap_err();
+        assert_eq!(err.code().unwrap().code(), "AuthFailure.SecretIdNotFound");
+    }
+}
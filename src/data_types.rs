@@ -30,9 +30,14 @@ pub enum ContentType {
     #[serde(rename = "application/json; charset=utf-8")]
     #[cfg_attr(feature = "clap", clap(name = "application/json; charset=utf-8"))]
     JSON,
+    /// GET 请求
+    #[lit = "application/x-www-form-urlencoded"]
+    #[serde(rename = "application/x-www-form-urlencoded")]
+    #[cfg_attr(feature = "clap", clap(name = "application/x-www-form-urlencoded"))]
+    FormUrlEncoded,
 }
 
-#[derive(Debug, Default, Clone, LiteralEnum, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, LiteralEnum, Serialize, Deserialize)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum Language {
     #[default]
@@ -54,11 +59,70 @@ pub enum Version {
     #[serde(rename = "2021-03-23")]
     #[cfg_attr(feature = "clap", clap(name = "2021-03-23"))]
     Version2021_03_23,
+    /// sts
+    #[lit = "2018-08-13"]
+    #[serde(rename = "2018-08-13")]
+    #[cfg_attr(feature = "clap", clap(name = "2018-08-13"))]
+    Version2018_08_13,
 }
 
-#[derive(Debug, Clone, LiteralEnum, Serialize, Deserialize)]
+/// 地域, DNSPod 的接口不需要, sts 等其它产品需要
+/// <https://cloud.tencent.com/document/api/1312/48197>
+#[derive(Debug, Default, Clone, LiteralEnum, Serialize, Deserialize)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
-pub enum Region {}
+pub enum Region {
+    /// 华南地区(广州)
+    #[default]
+    #[lit = "ap-guangzhou"]
+    #[serde(rename = "ap-guangzhou")]
+    #[cfg_attr(feature = "clap", clap(name = "ap-guangzhou"))]
+    ApGuangzhou,
+    /// 华东地区(上海)
+    #[lit = "ap-shanghai"]
+    #[serde(rename = "ap-shanghai")]
+    #[cfg_attr(feature = "clap", clap(name = "ap-shanghai"))]
+    ApShanghai,
+    /// 华东地区(南京)
+    #[lit = "ap-nanjing"]
+    #[serde(rename = "ap-nanjing")]
+    #[cfg_attr(feature = "clap", clap(name = "ap-nanjing"))]
+    ApNanjing,
+    /// 华北地区(北京)
+    #[lit = "ap-beijing"]
+    #[serde(rename = "ap-beijing")]
+    #[cfg_attr(feature = "clap", clap(name = "ap-beijing"))]
+    ApBeijing,
+    /// 西南地区(成都)
+    #[lit = "ap-chengdu"]
+    #[serde(rename = "ap-chengdu")]
+    #[cfg_attr(feature = "clap", clap(name = "ap-chengdu"))]
+    ApChengdu,
+    /// 西南地区(重庆)
+    #[lit = "ap-chongqing"]
+    #[serde(rename = "ap-chongqing")]
+    #[cfg_attr(feature = "clap", clap(name = "ap-chongqing"))]
+    ApChongqing,
+    /// 港澳台地区(中国香港)
+    #[lit = "ap-hongkong"]
+    #[serde(rename = "ap-hongkong")]
+    #[cfg_attr(feature = "clap", clap(name = "ap-hongkong"))]
+    ApHongkong,
+    /// 亚太东南(新加坡)
+    #[lit = "ap-singapore"]
+    #[serde(rename = "ap-singapore")]
+    #[cfg_attr(feature = "clap", clap(name = "ap-singapore"))]
+    ApSingapore,
+    /// 美国西部(硅谷)
+    #[lit = "na-siliconvalley"]
+    #[serde(rename = "na-siliconvalley")]
+    #[cfg_attr(feature = "clap", clap(name = "na-siliconvalley"))]
+    NaSiliconvalley,
+    /// 欧洲地区(法兰克福)
+    #[lit = "eu-frankfurt"]
+    #[serde(rename = "eu-frankfurt")]
+    #[cfg_attr(feature = "clap", clap(name = "eu-frankfurt"))]
+    EuFrankfurt,
+}
 
 #[derive(Debug, Default, Clone, LiteralEnum, Serialize, Deserialize)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
@@ -113,6 +177,109 @@ pub enum RecordLine {
     默认,
 }
 
+/// 记录状态
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, LiteralEnum, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum RecordStatus {
+    /// 启用
+    #[default]
+    #[cfg_attr(feature = "clap", clap(rename_all = "UPPER"))]
+    ENABLE,
+    /// 暂停
+    #[cfg_attr(feature = "clap", clap(rename_all = "UPPER"))]
+    DISABLE,
+}
+
+/// 解析记录的排序字段
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, LiteralEnum, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum SortField {
+    /// 主机头
+    #[default]
+    #[lit = "name"]
+    #[serde(rename = "name")]
+    #[cfg_attr(feature = "clap", clap(name = "name"))]
+    Name,
+    /// 线路
+    #[lit = "line"]
+    #[serde(rename = "line")]
+    #[cfg_attr(feature = "clap", clap(name = "line"))]
+    Line,
+    /// 记录类型
+    #[lit = "type"]
+    #[serde(rename = "type")]
+    #[cfg_attr(feature = "clap", clap(name = "type"))]
+    Type,
+    /// 记录值
+    #[lit = "value"]
+    #[serde(rename = "value")]
+    #[cfg_attr(feature = "clap", clap(name = "value"))]
+    Value,
+    /// 权重
+    #[lit = "weight"]
+    #[serde(rename = "weight")]
+    #[cfg_attr(feature = "clap", clap(name = "weight"))]
+    Weight,
+    /// MX 优先级
+    #[lit = "mx"]
+    #[serde(rename = "mx")]
+    #[cfg_attr(feature = "clap", clap(name = "mx"))]
+    MX,
+    /// TTL
+    #[lit = "ttl"]
+    #[serde(rename = "ttl")]
+    #[cfg_attr(feature = "clap", clap(name = "ttl"))]
+    TTL,
+    /// 最后一次更新时间
+    #[lit = "updated_on"]
+    #[serde(rename = "updated_on")]
+    #[cfg_attr(feature = "clap", clap(name = "updated_on"))]
+    UpdatedOn,
+}
+
+/// 排序方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, LiteralEnum, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum SortType {
+    /// 正序
+    #[default]
+    #[cfg_attr(feature = "clap", clap(rename_all = "UPPER"))]
+    ASC,
+    /// 倒序
+    #[cfg_attr(feature = "clap", clap(rename_all = "UPPER"))]
+    DESC,
+}
+
+/// 键值对, 可以从 `key=value` 解析, 如 `ttl=600`
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyValue {
+    /// 键
+    pub Key: String,
+    /// 值
+    pub Value: String,
+}
+
+impl KeyValue {
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            Key: key.into(),
+            Value: value.into(),
+        }
+    }
+}
+
+impl FromStr for KeyValue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected `key=value`, got `{s}`"))?;
+        Ok(Self::new(key, value))
+    }
+}
+
 /// 域名分组类型 ALL，默认为ALL  
 /// 可取值为: MINE，SHARE，ISMARK，PAUSE，VIP，RECENT，SHARE_OUT，FREE
 #[allow(non_camel_case_types)]
@@ -219,7 +386,7 @@ impl<'de> Deserialize<'de> for DnsPodDate {
 #[should_panic]
 fn should_panic() {
     // https://serde.rs/custom-date-format.html
-    const FORMAT: &'static str = "%Y-%m-%d %H:%M:%S";
+    const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
     let s = "\"0000-00-00 00:00:00\"";
     let dt = Utc.datetime_from_str(s, FORMAT).unwrap();
 }
@@ -239,3 +406,34 @@ fn test() {
     println!("d: {:?}", d);
     println!("s: {:?}", s);
 }
+
+#[test]
+fn round_trip_dates() {
+    let s = "\"0000-01-01 00:00:00\"";
+    let t: Timestamp = serde_json::from_str(s).unwrap();
+    assert_eq!(t, "0000-01-01 00:00:00");
+    assert_eq!(serde_json::to_string(&t).unwrap(), s);
+
+    let s = "\"2023-09-03\"";
+    let d: Date = serde_json::from_str(s).unwrap();
+    assert_eq!(d, "2023-09-03");
+    assert_eq!(serde_json::to_string(&d).unwrap(), s);
+
+    // 合法的日期原样输出; 服务器返回的 0000-00-00 无法解析, date 为 None, 序列化后仍为 0000-00-00
+    for (s, parsed) in [("\"2023-09-03\"", true), ("\"0000-00-00\"", false)] {
+        let d: DnsPodDate = serde_json::from_str(s).unwrap();
+        assert_eq!(d.date.is_some(), parsed);
+        assert_eq!(serde_json::to_string(&d).unwrap(), s);
+    }
+}
+
+#[test]
+fn key_value() {
+    assert_eq!("ttl=600".parse(), Ok(KeyValue::new("ttl", "600")));
+    assert_eq!("remark=a=b".parse(), Ok(KeyValue::new("remark", "a=b")));
+    assert!("ttl".parse::<KeyValue>().is_err());
+    assert_eq!(
+        serde_json::to_value(KeyValue::new("ttl", "600")).unwrap(),
+        serde_json::json!({"Key": "ttl", "Value": "600"})
+    );
+}
@@ -0,0 +1,88 @@
+//! 错误类型
+
+use std::fmt;
+
+use crate::data_types::Language;
+use crate::error_code::ErrorCode;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// 请求参数无法序列化
+    Serialize(serde_json::Error),
+    /// 请求参数不合法, 发送前由 [`ExtractCommonParams::validate`](crate::ExtractCommonParams::validate) 检查
+    InvalidParams(String),
+    /// 网络请求出错
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+    /// 服务器返回的错误 <https://cloud.tencent.com/document/api/1427/56192>
+    Api {
+        code: ErrorCode,
+        message: String,
+        /// 唯一请求 ID, 定位问题时需要提供该次请求的 RequestId
+        request_id: String,
+        /// 错误码描述的语言, 即 Client 的语言
+        language: Language,
+    },
+    /// 返回结果无法解析, `body` 为服务器返回的原始内容
+    Decode {
+        source: serde_json::Error,
+        body: String,
+    },
+    /// 获取密钥失败
+    Credential(String),
+}
+
+impl Error {
+    /// 服务器返回错误时, 该次请求的 RequestId
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::Api { request_id, .. } => Some(request_id),
+            _ => None,
+        }
+    }
+
+    /// 服务器返回的错误码
+    pub fn code(&self) -> Option<&ErrorCode> {
+        match self {
+            Self::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(e) => write!(f, "failed to serialize request: {e}"),
+            Self::InvalidParams(e) => write!(f, "invalid request: {e}"),
+            Self::Transport(e) => write!(f, "transport error: {e}"),
+            Self::Api {
+                code,
+                message,
+                request_id,
+                language,
+            } => write!(
+                f,
+                "{}: {message} ({}) RequestId: {request_id}",
+                code.code(),
+                code.description(*language)
+            ),
+            Self::Decode { source, body } => {
+                write!(f, "failed to decode response: {source}, body: {body}")
+            }
+            Self::Credential(e) => write!(f, "credential error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Serialize(e) => Some(e),
+            Self::Transport(e) => Some(e.as_ref()),
+            Self::InvalidParams(_) | Self::Api { .. } | Self::Credential(_) => None,
+            Self::Decode { source, .. } => Some(source),
+        }
+    }
+}
@@ -2,257 +2,533 @@
 //! <https://cloud.tencent.com/document/api/1427/56192>
 //!
 
-use serde::Deserialize;
-use serde::Serialize;
+use std::fmt;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ErrorCode {
-    #[serde(rename = "Code")]
-    code: String,
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::data_types::Language;
+
+macro_rules! error_codes {
+    (
+        $(
+            $variant: ident = $code: literal => ($zh: literal, $en: literal),
+        )*
+    ) => {
+        /// 服务器返回的错误码, 不认识的错误码为 [`ErrorCode::Unknown`]
+        ///
+        /// 变量名为去掉 `.` 的错误码, 比如 `AuthFailure.SignatureExpire` 对应 [`ErrorCode::AuthFailureSignatureExpire`]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum ErrorCode {
+            $(
+                #[doc = concat!("`", $code, "`: ", $zh)]
+                $variant,
+            )*
+            /// 未收录的错误码
+            Unknown(String),
+        }
+
+        impl ErrorCode {
+            /// 错误码, 如 `AuthFailure.SignatureExpire`
+            pub fn code(&self) -> &str {
+                match self {
+                    $( Self::$variant => $code, )*
+                    Self::Unknown(code) => code,
+                }
+            }
+
+            /// 错误码的描述
+            pub fn description(&self, language: Language) -> &'static str {
+                match (self, language) {
+                    $(
+                        (Self::$variant, Language::ZhCN) => $zh,
+                        (Self::$variant, Language::EnUS) => $en,
+                    )*
+                    (Self::Unknown(_), Language::ZhCN) => "未知错误",
+                    (Self::Unknown(_), Language::EnUS) => "Unknown error",
+                }
+            }
+        }
+
+        impl From<&str> for ErrorCode {
+            fn from(code: &str) -> Self {
+                match code {
+                    $( $code => Self::$variant, )*
+                    _ => Self::Unknown(code.to_string()),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! error_categories {
+    ($($variant: ident,)*) => {
+        /// 错误码的分类, 即错误码第一个 `.` 之前的部分, 不认识的分类为 [`ErrorCategory::Other`]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum ErrorCategory {
+            $(
+                #[doc = concat!("`", stringify!($variant), "`")]
+                $variant,
+            )*
+            /// 其他分类
+            Other(String),
+        }
+
+        impl ErrorCategory {
+            /// 分类名, 如 `AuthFailure`
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( Self::$variant => stringify!($variant), )*
+                    Self::Other(category) => category,
+                }
+            }
+        }
+
+        impl From<&str> for ErrorCategory {
+            fn from(category: &str) -> Self {
+                match category {
+                    $( stringify!($variant) => Self::$variant, )*
+                    _ => Self::Other(category.to_string()),
+                }
+            }
+        }
+    };
+}
+
+error_categories! {
+    AuthFailure,
+    FailedOperation,
+    InternalError,
+    InvalidParameter,
+    InvalidParameterValue,
+    LimitExceeded,
+    MissingParameter,
+    OperationDenied,
+    RequestLimitExceeded,
+    ResourceNotFound,
+    ResourceUnavailable,
+    ServiceUnavailable,
+    UnauthorizedOperation,
+    UnsupportedOperation,
+}
+
+impl fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl ErrorCode {
+    /// 中文描述, 同 `description(Language::ZhCN)`
     pub fn to_description(&self) -> &'static str {
-        match self.code.as_str() {
-            // 公共错误码
-            "ActionOffline" => "接口已下线。",
-            "AuthFailure.InvalidAuthorization" => "请求头部的 Authorization 不符合腾讯云标准。",
-            "AuthFailure.InvalidSecretId" => "密钥非法（不是云 API 密钥类型）。",
-            "AuthFailure.MFAFailure" => "MFA 错误。",
-            "AuthFailure.SecretIdNotFound" => "密钥不存在。请在 控制台 检查密钥是否已被删除或者禁用，如状态正常，请检查密钥是否填写正确，注意前后不得有空格。",
-            "AuthFailure.SignatureExpire" => "签名过期。Timestamp 和服务器时间相差不得超过五分钟，请检查本地时间是否和标准时间同步。",
-            "AuthFailure.SignatureFailure" => "签名错误。签名计算错误，请对照调用方式中的签名方法文档检查签名计算过程。",
-            "AuthFailure.TokenFailure" => "token 错误。",
-            "AuthFailure.UnauthorizedOperation" => "请求未授权。请参考 CAM 文档对鉴权的说明。",
-            "DryRunOperation" => "DryRun 操作，代表请求将会是成功的，只是多传了 DryRun 参数。",
-            "FailedOperation" => "操作失败。",
-            "InternalError" => "内部错误。",
-            "InvalidAction" => "接口不存在。",
-            "InvalidParameter" => "参数错误（包括参数格式、类型等错误）。",
-            "InvalidParameterValue" => "参数取值错误。",
-            "InvalidRequest" => "请求 body 的 multipart 格式错误。",
-            "IpInBlacklist" => "IP地址在黑名单中。",
-            "IpNotInWhitelist" => "IP地址不在白名单中。",
-            "LimitExceeded" => "超过配额限制。",
-            "MissingParameter" => "缺少参数。",
-            "NoSuchProduct" => "产品不存在",
-            "NoSuchVersion" => "接口版本不存在。",
-            "RequestLimitExceeded" => "请求的次数超过了频率限制。",
-            "RequestLimitExceeded.GlobalRegionUinLimitExceeded" => "主账号超过频率限制。",
-            "RequestLimitExceeded.IPLimitExceeded" => "IP限频。",
-            "RequestLimitExceeded.UinLimitExceeded" => "主账号限频。",
-            "RequestSizeLimitExceeded" => "请求包超过限制大小。",
-            "ResourceInUse" => "资源被占用。",
-            "ResourceInsufficient" => "资源不足。",
-            "ResourceNotFound" => "资源不存在。",
-            "ResourceUnavailable" => "资源不可用。",
-            "ResponseSizeLimitExceeded" => "返回包超过限制大小。",
-            "ServiceUnavailable" => "当前服务暂时不可用。",
-            "UnauthorizedOperation" => "未授权操作。",
-            "UnknownParameter" => "未知参数错误，用户多传未定义的参数会导致错误。",
-            "UnsupportedOperation" => "操作不支持。",
-            "UnsupportedProtocol" => "http(s) 请求协议错误，只支持 GET 和 POST 请求。",
-            "UnsupportedRegion" => "接口不支持所传地域。",
-
-            // 业务错误码
-            "AuthFailure" => "CAM签名/鉴权错误。",
-            "FailedOperation.AccountIsLocked" => "抱歉，该帐户已经被锁定。",
-            "FailedOperation.ContainsPersonalVip" => "您的帐号下包含个人豪华域名，不能直接升级，请联系销售。",
-            "FailedOperation.CouponForFreeDomain" => "此优惠券只能被免费域名使用。",
-            "FailedOperation.CouponNotSupported" => "您的帐户不满足使用此优惠券的条件。",
-            "FailedOperation.CouponTypeAlreadyUsed" => "域名已经使用过该类型的礼券了，不能重复使用。",
-            "FailedOperation.DomainExists" => "该域名已在您的列表中，无需重复添加。",
-            "FailedOperation.DomainInEnterpriseMailAccount" => "该域名属于企业邮用户",
-            "FailedOperation.DomainIsKeyDomain" => "该域名为腾讯云 DNSPod 重点保护资源，为了避免误操作造成的业务影响，域名禁止自行操作删除。如果您确认需要删除域名，请先联系您的客户经理，我们将竭诚为您提供技术支持。",
-            "FailedOperation.DomainIsLocked" => "锁定域名不能进行此操作。",
-            "FailedOperation.DomainIsPersonalType" => "域名已升级为个人套餐，但目前位于企业账号，请与销售联系。",
-            "FailedOperation.DomainIsSpam" => "封禁域名不能进行此操作。",
-            "FailedOperation.DomainIsVip" => "VIP域名不能进行此操作。",
-            "FailedOperation.DomainNotInService" => "当前域名还未使用 DNSPod 的解析服务，我们无法获取解析量数据。",
-            "FailedOperation.DomainOwnedByOtherUser" => "该域名已被其他账号添加，可在域名列表中添加取回。",
-            "FailedOperation.EipCheckFailed" => "校验公网 IP 发生异常。",
-            "FailedOperation.FrequencyLimit" => "您操作过于频繁，请稍后重试",
-            "FailedOperation.FunctionNotAllowedApply" => "此功能暂停申请，请稍候重试。",
-            "FailedOperation.GetWhoisFailed" => "获取不到域名信息，可能域名非法或网络故障，请稍后再试。",
-            "FailedOperation.InsufficientBalance" => "账户余额不足。",
-            "FailedOperation.LoginAreaNotAllowed" => "帐号异地登录，请求被拒绝。",
-            "FailedOperation.LoginFailed" => "登录失败，请检查账号和密码是否正确。",
-            "FailedOperation.LoginTimeout" => "登录已经超时，请重新登录。",
-            "FailedOperation.MobileNotVerified" => "用户手机没有通过验证。",
-            "FailedOperation.NotBatchTaskOwner" => "权限错误，您无法查看该任务的详情。",
-            "FailedOperation.NotDomainOwner" => "域名不在您的名下。",
-            "FailedOperation.NotRealNamedUser" => "未实名认证用户，请先完成实名认证再操作。",
-            "FailedOperation.NotResourceOwner" => "您没有权限操作此资源。",
-            "FailedOperation.OrderCanNotPay" => "您不能付款此订单。",
-            "FailedOperation.OrderHasPaid" => "此订单已经付过款。",
-            "FailedOperation.ResourceNotBind" => "资源未绑定域名。",
-            "FailedOperation.TemporaryError" => "请求量统计数据暂时不可用，请稍后再试。",
-            "FailedOperation.TransferToEnterpriseDenied" => "不能转移到企业账号。",
-            "FailedOperation.TransferToPersonDenied" => "不能转移到个人账号。",
-            "FailedOperation.UnknowError" => "操作未响应，请稍后重试。",
-            "FailedOperation.VerifyingBillExists" => "域名已经提交过订单并且正在审核中，请稍候。",
-            "InvalidParameter.AccountIsBanned" => "您的账号已被系统封禁，如果您有任何疑问请与我们联系。",
-            "InvalidParameter.ActionInvalid" => "无效的操作。",
-            "InvalidParameter.ActionSuccess" => "操作已经成功完成。",
-            "InvalidParameter.Activity" => "用户未实名。",
-            "InvalidParameter.BatchDomainCreateActionError" => "创建批量域名任务失败，原因：内部错误。",
-            "InvalidParameter.BatchRecordCreateActionError" => "创建批量记录任务失败，原因：内部错误。",
-            "InvalidParameter.BatchRecordModifyActionError" => "批量修改记录任务失败，原因：内部错误。",
-            "InvalidParameter.BatchRecordModifyActionInvalidValue" => "记录值无效。",
-            "InvalidParameter.BatchRecordRemoveActionError" => "批量删除记录任务失败，原因：内部错误。",
-            "InvalidParameter.BatchRecordReplaceActionError" => "批量替换记录任务失败，原因：内部错误。",
-            "InvalidParameter.BatchTaskCountLimit" => "超过批量任务数上限。",
-            "InvalidParameter.BatchTaskNotExist" => "任务不存在，无法获取任务详情。",
-            "InvalidParameter.BillNumberInvalid" => "订单号码不正确。",
-            "InvalidParameter.BrowserNull" => "浏览器字段为空。",
-            "InvalidParameter.Common" => "您操作过于频繁，请稍后重试。",
-            "InvalidParameter.CustomMessage" => "自定义错误信息。",
-            "InvalidParameter.DataExpired" => "数据过期,请重新提交。",
-            "InvalidParameter.DataInvalid" => "data 无效。",
-            "InvalidParameter.DealTypeInvalid" => "订单类型无效。",
-            "InvalidParameter.DnsDealDomainUpgraded" => "域名已升级至付费套餐，无法完成下单。",
-            "InvalidParameter.DnsDealLocked" => "相关服务已有其他未完成的订单，请先将原订单支付或取消后，才可提交新的订单。",
-            "InvalidParameter.DnsInvalidDeal" => "订单数据非法。",
-            "InvalidParameter.DnssecAddCnameError" => "该域名开启了 DNSSEC，不允许添加 @ 子域名 CNAME、显性 URL 或者隐性 URL 记录。",
-            "InvalidParameter.DomainAliasExists" => "别名已经存在。",
-            "InvalidParameter.DomainAliasIdInvalid" => "别名编号错误。",
-            "InvalidParameter.DomainIdInvalid" => "域名编号不正确。",
-            "InvalidParameter.DomainInBlackList" => "该域名涉及违法违规黑名单，无法进行该操作",
-            "InvalidParameter.DomainInEffectOrInvalidated" => "不允许操作生效中或失效中的域名。",
-            "InvalidParameter.DomainInvalid" => "域名不正确，请输入主域名，如 dnspod.cn。",
-            "InvalidParameter.DomainIsAliaser" => "此域名是其它域名的别名。",
-            "InvalidParameter.DomainIsModifyingDns" => "该域名已有同类型操作未完成，无法执行该操作。",
-            "InvalidParameter.DomainIsMyAlias" => "此域名是自己域名的别名",
-            "InvalidParameter.DomainIsNotlocked" => "域名没有锁定。",
-            "InvalidParameter.DomainNotAllowedLock" => "暂停域名不支持锁定。",
-            "InvalidParameter.DomainNotAllowedModifyRecords" => "处于生效中/失效中的域名，不允许变更解析记录。",
-            "InvalidParameter.DomainNotBeian" => "该域名未备案，无法添加 URL 记录。",
-            "InvalidParameter.DomainNotReged" => "域名还没有注册，无法添加。",
-            "InvalidParameter.DomainNotVip" => "原域名不是VIP域名，无法替换。",
-            "InvalidParameter.DomainRecordExist" => "记录已经存在，无需再次添加。",
-            "InvalidParameter.DomainSelfNoCopy" => "域名自己无需进行复制。",
-            "InvalidParameter.DomainTooLong" => "域名过长。",
-            "InvalidParameter.DomainTypeInvalid" => "域名类型错误。",
-            "InvalidParameter.DomainsEmpty" => "没有提交任何域名。",
-            "InvalidParameter.EmailInvalid" => "邮箱地址不正确。",
-            "InvalidParameter.EmailNotVerified" => "抱歉，您的账户还没有通过邮箱验证。",
-            "InvalidParameter.EmailOrQqInvalid" => "请输入合法的邮箱或者uin。",
-            "InvalidParameter.EmailSame" => "域名已经在该账号下。",
-            "InvalidParameter.GoodsChildTypeInvalid" => "商品子类型无效。",
-            "InvalidParameter.GoodsNumInvalid" => "商品数量无效。",
-            "InvalidParameter.GoodsTypeInvalid" => "商品类型无效。",
-            "InvalidParameter.GradeNotCopy" => "当前域名等级低于源域名的等级，无法进行复制。",
-            "InvalidParameter.GroupIdInvalid" => "分组编号不正确。",
-            "InvalidParameter.GroupNameExists" => "同名分组已经存在。",
-            "InvalidParameter.GroupNameInvalid" => "分组名为1-17个字符。",
-            "InvalidParameter.HasPendingApply" => "您已经提交过申请并且正在审核中，请耐心等候。",
-            "InvalidParameter.IllegalNewDeal" => "该订单存在冲突或参数有误，无法完成支付，请重新购买。",
-            "InvalidParameter.InnerTaskNotExist" => "任务不存在。",
-            "InvalidParameter.InvalidCoupon" => "礼券代码无效。",
-            "InvalidParameter.InvalidDealName" => "请输入正确的订单号。",
-            "InvalidParameter.InvalidSecretId" => "无效密钥 ID。",
-            "InvalidParameter.InvalidSignature" => "无效签名。",
-            "InvalidParameter.InvalidTime" => "无效的时间。",
-            "InvalidParameter.InvalidWeight" => "权重不合法。请输入0~100的整数。",
-            "InvalidParameter.IpsExceedLimit" => "ips 过长。",
-            "InvalidParameter.JobGreaterThanLimit" => "单次任务数量超过上限。",
-            "InvalidParameter.LockDaysInvalid" => "锁定天数不正确。",
-            "InvalidParameter.LoginTokenIdError" => "Token 的 ID 不正确。",
-            "InvalidParameter.LoginTokenNotExists" => "传入的 Token 不存在。",
-            "InvalidParameter.LoginTokenValidateFailed" => "Token 验证失败。",
-            "InvalidParameter.MobileNotVerified" => "抱歉，您的账户还没有通过手机验证。",
-            "InvalidParameter.MxInvalid" => "MX优先级不正确。",
-            "InvalidParameter.NewPackageTypeInvalid" => "新套餐类型无效。",
-            "InvalidParameter.OffsetInvalid" => "分页起始数量错误。",
-            "InvalidParameter.OpenidInvalid" => "无效的openid。",
-            "InvalidParameter.OperateFailed" => "操作失败，请稍候再试。",
-            "InvalidParameter.OperationIsTooFrequent" => "当前操作过于频繁，请 1 分钟后重试。",
-            "InvalidParameter.OptypeNotSupported" => "不支持的操作类型。",
-            "InvalidParameter.OtherAccountUnrealName" => "对方账号未实名认证，请先完成实名认证再操作。",
-            "InvalidParameter.ParamInvalid" => "param 格式错误。",
-            "InvalidParameter.ParamsIllegal" => "参数不合法，请求被拒绝。",
-            "InvalidParameter.ParamsMissing" => "参数错误。",
-            "InvalidParameter.PermissionDenied" => "鉴权失败。",
-            "InvalidParameter.QcloudUinInvalid" => "用户UIN无效。",
-            "InvalidParameter.QuhuiTxtNotMatch" => "TXT 记录无法匹配，请确认记录值是否准确，并再次验证。",
-            "InvalidParameter.QuhuiTxtRecordWait" => "TXT 记录未设置或暂未生效，请稍后重试。",
-            "InvalidParameter.RealNameUser" => "已实名用户。",
-            "InvalidParameter.RecordIdInvalid" => "记录编号错误。",
-            "InvalidParameter.RecordLineInvalid" => "记录线路不正确。",
-            "InvalidParameter.RecordTypeInvalid" => "记录类型不正确。",
-            "InvalidParameter.RecordValueInvalid" => "记录的值不正确。",
-            "InvalidParameter.RecordValueLengthInvalid" => "解析记录值过长。",
-            "InvalidParameter.RecordsEmpty" => "没有提交任何记录。",
-            "InvalidParameter.RemarkLengthExceeded" => "备注信息超出字符限制。",
-            "InvalidParameter.RemarkTooLong" => "备注过长（不能超过200个字）。",
-            "InvalidParameter.RequestIpLimited" => "您的IP非法，请求被拒绝。",
-            "InvalidParameter.ResultMoreThan500" => "搜索结果大于500条，请增加关键字。",
-            "InvalidParameter.SharedUsersUnrealName" => "共享用户中包含未实名认证用户。",
-            "InvalidParameter.StatusCodeInvalid" => "状态代码不正确。",
-            "InvalidParameter.SubdomainInvalid" => "子域名不正确。",
-            "InvalidParameter.TaskNotCompleted" => "任务未完成。",
-            "InvalidParameter.TimeSpanInvalid" => "时长无效。",
-            "InvalidParameter.TimestampExpired" => "时间戳已过期。",
-            "InvalidParameter.TooManyInvalidDomains" => "当前帐号下的无效域名过多，暂时无法使用该功能。请将已有域名的 DNS 服务器正确指向 DNSPod 之后，再尝试添加。",
-            "InvalidParameter.ToolsDomainInvalid" => "域名无效。",
-            "InvalidParameter.UnLockCodeExpired" => "解锁代码已失效。",
-            "InvalidParameter.UnLockCodeInvalid" => "解锁代码不正确。",
-            "InvalidParameter.UnrealNameUser" => "未实名认证用户，请先完成实名认证再操作。",
-            "InvalidParameter.UrlValueIllegal" => "很抱歉，您要添加的URL的内容不符合DNSPod解析服务条款，URL添加/启用失败，如需帮助请联系技术支持。",
-            "InvalidParameter.UserAlreadyLocked" => "账号已经被锁定。",
-            "InvalidParameter.UserAreaInvalid" => "对方账户非国内站用户。",
-            "InvalidParameter.UserNotExists" => "用户不存在。",
-            "InvalidParameter.UuidInvalid" => "无效的uuid。",
-            "InvalidParameterValue.DomainGradeInvalid" => "域名等级不正确。",
-            "InvalidParameterValue.DomainNotExists" => "当前域名有误，请返回重新操作。",
-            "InvalidParameterValue.LimitInvalid" => "分页长度数量错误。",
-            "InvalidParameterValue.UpgradeTermInvalid" => "升级年限不正确。",
-            "InvalidParameterValue.UserIdInvalid" => "用户编号不正确。",
-            "LimitExceeded.AAAACountLimit" => "AAAA记录数量超出限制。",
-            "LimitExceeded.AtNsRecordLimit" => "@的NS记录只能设置为默认线路。",
-            "LimitExceeded.DomainAliasCountExceeded" => "别名数量已经达到限制。",
-            "LimitExceeded.DomainAliasNumberLimit" => "当前绑定别名数量已达到限制。",
-            "LimitExceeded.FailedLoginLimitExceeded" => "登录失败次数过多已被系统封禁。",
-            "LimitExceeded.GroupNumberLimit" => "已经达到最大分组数量限制。",
-            "LimitExceeded.HiddenUrlExceeded" => "该域名使用的套餐不支持隐性URL转发或数量已达上限，如需要使用，请去商城购买。",
-            "LimitExceeded.NsCountLimit" => "NS记录数量超出限制。",
-            "LimitExceeded.OffsetExceeded" => "分页起始数量过大。",
-            "LimitExceeded.RecordTtlLimit" => "记录的TTL值超出了限制。",
-            "LimitExceeded.SrvCountLimit" => "SRV记录数量超出限制。",
-            "LimitExceeded.SubdomainLevelLimit" => "子域名级数超出限制。",
-            "LimitExceeded.SubdomainRollLimit" => "子域名负载均衡数量超出限制。",
-            "LimitExceeded.SubdomainWcardLimit" => "泛解析级数超出限制。",
-            "LimitExceeded.UrlCountLimit" => "该域名的显性URL转发数量已达上限，如需继续使用，请去商城购买。",
-            "OperationDenied" => "操作被拒绝。",
-            "OperationDenied.AccessDenied" => "您没有权限执行此操作。",
-            "OperationDenied.AgentDenied" => "代理不能使用此功能。",
-            "OperationDenied.AgentSubordinateDenied" => "代理名下的用户不能使用此功能。",
-            "OperationDenied.CancelBillNotAllowed" => "此订单不能取消。",
-            "OperationDenied.DeleteUsingRecordLineNotAllowed" => "该线路正在使用当中，不能删除。",
-            "OperationDenied.DomainOwnerAllowedOnly" => "仅域名所有者可进行此操作。",
-            "OperationDenied.IPInBlacklistNotAllowed" => "抱歉，不允许添加黑名单中的IP。",
-            "OperationDenied.MonitorCallbackNotEnabled" => "抱歉，您的域名等级不支持D监控通知回调功能。",
-            "OperationDenied.NoPermissionToOperateDomain" => "当前域名无权限，请返回域名列表。",
-            "OperationDenied.NotAdmin" => "您不是管理用户。",
-            "OperationDenied.NotAgent" => "您不是代理用户。",
-            "OperationDenied.NotGrantedByOwner" => "您还没有获取到授权，无法执行此操作。",
-            "OperationDenied.NotManagedUser" => "不是您名下用户。",
-            "OperationDenied.NotOrderOwner" => "您没有权限操作此订单。",
-            "OperationDenied.NotResourceOwner" => "您没有权限操作此资源。",
-            "OperationDenied.PersonalCouponNotAllowed" => "此礼券为个人礼券，请使用企业礼券。",
-            "OperationDenied.PostRequestAcceptOnly" => "只支持 POST 方法提交数据。",
-            "OperationDenied.ResourceNotAllowRenew" => "该资源不允许续费。",
-            "OperationDenied.VipDomainAllowed" => "企业用户的域名需要升级到VIP才能解析。",
-            "RequestLimitExceeded.BatchTaskLimit" => "您的IP添加了过多任务，请稍后重试。",
-            "RequestLimitExceeded.CreateDomainLimit" => "您的帐号在短时间内添加了大量的域名，请控制添加频率。",
-            "RequestLimitExceeded.RequestLimitExceeded" => "API请求次数超出限制。",
-            "ResourceNotFound.NoDataOfDomain" => "域名列表为空。",
-            "ResourceNotFound.NoDataOfDomainAlias" => "没有域名别名。",
-            "ResourceNotFound.NoDataOfGift" => "还没有任何礼券。",
-            "ResourceNotFound.NoDataOfRecord" => "记录列表为空。",
-            "ResourcesSoldOut" => "资源售罄。",
-
-            _ => "未知错误",
+        self.description(Language::ZhCN)
+    }
+
+    /// 错误码的分类, 比如 `AuthFailure.SignatureExpire` 属于 [`ErrorCategory::AuthFailure`]
+    pub fn category(&self) -> ErrorCategory {
+        let code = self.code();
+        code.split_once('.')
+            .map_or(code, |(category, _)| category)
+            .into()
+    }
+
+    /// 服务器繁忙或请求过于频繁, 稍后重试可能成功
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::InternalError
+                | Self::ServiceUnavailable
+                | Self::FailedOperationFrequencyLimit
+                | Self::InvalidParameterOperationIsTooFrequent
+        ) || self.category() == ErrorCategory::RequestLimitExceeded
+    }
+
+    /// 密钥、签名或权限错误
+    pub fn is_auth(&self) -> bool {
+        matches!(
+            self.category(),
+            ErrorCategory::AuthFailure | ErrorCategory::UnauthorizedOperation
+        )
+    }
+
+    /// 域名或记录不存在, 比如 `ResourceNotFound.NoDataOfRecord`.
+    /// `InvalidParameter.RecordIdInvalid` 是记录编号格式错误, 不算不存在
+    pub fn is_not_found(&self) -> bool {
+        *self == Self::InvalidParameterValueDomainNotExists
+            || self.category() == ErrorCategory::ResourceNotFound
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(code: String) -> Self {
+        match Self::from(code.as_str()) {
+            Self::Unknown(_) => Self::Unknown(code),
+            known => known,
         }
     }
 }
+
+impl std::str::FromStr for ErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Ok(code.into())
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+error_codes! {
+    // 公共错误码
+    ActionOffline = "ActionOffline" => ("接口已下线。", "The API has been taken offline."),
+
+    AuthFailureInvalidAuthorization = "AuthFailure.InvalidAuthorization" => ("请求头部的 Authorization 不符合腾讯云标准。", "The Authorization in the request header does not comply with the Tencent Cloud standard."),
+    AuthFailureInvalidSecretId = "AuthFailure.InvalidSecretId" => ("密钥非法（不是云 API 密钥类型）。", "Invalid key (not a TencentCloud API key)."),
+    AuthFailureMFAFailure = "AuthFailure.MFAFailure" => ("MFA 错误。", "MFA error."),
+    AuthFailureSecretIdNotFound = "AuthFailure.SecretIdNotFound" => ("密钥不存在。请在 控制台 检查密钥是否已被删除或者禁用，如状态正常，请检查密钥是否填写正确，注意前后不得有空格。", "The key does not exist. Please check in the console whether the key has been deleted or disabled; if it is normal, check whether it is entered correctly without leading or trailing spaces."),
+    AuthFailureSignatureExpire = "AuthFailure.SignatureExpire" => ("签名过期。Timestamp 和服务器时间相差不得超过五分钟，请检查本地时间是否和标准时间同步。", "The signature has expired. Timestamp must be within five minutes of the server time; please check whether the local time is synchronized with the standard time."),
+    AuthFailureSignatureFailure = "AuthFailure.SignatureFailure" => ("签名错误。签名计算错误，请对照调用方式中的签名方法文档检查签名计算过程。", "Invalid signature. Please check the signature calculation against the signature method documentation."),
+    AuthFailureTokenFailure = "AuthFailure.TokenFailure" => ("token 错误。", "Token error."),
+    AuthFailureUnauthorizedOperation = "AuthFailure.UnauthorizedOperation" => ("请求未授权。请参考 CAM 文档对鉴权的说明。", "The request is not authorized. Please refer to the CAM documentation on authentication."),
+
+    DryRunOperation = "DryRunOperation" => ("DryRun 操作，代表请求将会是成功的，只是多传了 DryRun 参数。", "DryRun operation: the request would have succeeded, but the DryRun parameter was passed."),
+
+    FailedOperation = "FailedOperation" => ("操作失败。", "Operation failed."),
+
+    InternalError = "InternalError" => ("内部错误。", "Internal error."),
+
+    InvalidAction = "InvalidAction" => ("接口不存在。", "The API does not exist."),
+
+    InvalidParameter = "InvalidParameter" => ("参数错误（包括参数格式、类型等错误）。", "Parameter error (including format and type errors)."),
+
+    InvalidParameterValue = "InvalidParameterValue" => ("参数取值错误。", "Invalid parameter value."),
+
+    InvalidRequest = "InvalidRequest" => ("请求 body 的 multipart 格式错误。", "The multipart format of the request body is invalid."),
+
+    IpInBlacklist = "IpInBlacklist" => ("IP地址在黑名单中。", "The IP address is in the blocklist."),
+
+    IpNotInWhitelist = "IpNotInWhitelist" => ("IP地址不在白名单中。", "The IP address is not in the allowlist."),
+
+    LimitExceeded = "LimitExceeded" => ("超过配额限制。", "Quota limit exceeded."),
+
+    MissingParameter = "MissingParameter" => ("缺少参数。", "Missing parameter."),
+
+    NoSuchProduct = "NoSuchProduct" => ("产品不存在", "The product does not exist."),
+
+    NoSuchVersion = "NoSuchVersion" => ("接口版本不存在。", "The API version does not exist."),
+
+    RequestLimitExceeded = "RequestLimitExceeded" => ("请求的次数超过了频率限制。", "The number of requests exceeds the frequency limit."),
+    RequestLimitExceededGlobalRegionUinLimitExceeded = "RequestLimitExceeded.GlobalRegionUinLimitExceeded" => ("主账号超过频率限制。", "The root account exceeds the frequency limit."),
+    RequestLimitExceededIPLimitExceeded = "RequestLimitExceeded.IPLimitExceeded" => ("IP限频。", "The IP is rate limited."),
+    RequestLimitExceededUinLimitExceeded = "RequestLimitExceeded.UinLimitExceeded" => ("主账号限频。", "The root account is rate limited."),
+
+    RequestSizeLimitExceeded = "RequestSizeLimitExceeded" => ("请求包超过限制大小。", "The request size exceeds the limit."),
+
+    ResourceInUse = "ResourceInUse" => ("资源被占用。", "The resource is in use."),
+
+    ResourceInsufficient = "ResourceInsufficient" => ("资源不足。", "Insufficient resources."),
+
+    ResourceNotFound = "ResourceNotFound" => ("资源不存在。", "The resource does not exist."),
+
+    ResourceUnavailable = "ResourceUnavailable" => ("资源不可用。", "The resource is unavailable."),
+
+    ResponseSizeLimitExceeded = "ResponseSizeLimitExceeded" => ("返回包超过限制大小。", "The response size exceeds the limit."),
+
+    ServiceUnavailable = "ServiceUnavailable" => ("当前服务暂时不可用。", "The service is temporarily unavailable."),
+
+    UnauthorizedOperation = "UnauthorizedOperation" => ("未授权操作。", "Unauthorized operation."),
+
+    UnknownParameter = "UnknownParameter" => ("未知参数错误，用户多传未定义的参数会导致错误。", "Unknown parameter. Passing undefined parameters causes this error."),
+
+    UnsupportedOperation = "UnsupportedOperation" => ("操作不支持。", "Unsupported operation."),
+
+    UnsupportedProtocol = "UnsupportedProtocol" => ("http(s) 请求协议错误，只支持 GET 和 POST 请求。", "Unsupported HTTP(S) request protocol. Only GET and POST requests are supported."),
+
+    UnsupportedRegion = "UnsupportedRegion" => ("接口不支持所传地域。", "The API does not support the given region."),
+
+    // 业务错误码
+    AuthFailure = "AuthFailure" => ("CAM签名/鉴权错误。", "CAM signature/authentication error."),
+
+    FailedOperationAccountIsLocked = "FailedOperation.AccountIsLocked" => ("抱歉，该帐户已经被锁定。", "Sorry, this account has been locked."),
+    FailedOperationContainsPersonalVip = "FailedOperation.ContainsPersonalVip" => ("您的帐号下包含个人豪华域名，不能直接升级，请联系销售。", "Your account contains personal premium domains and cannot be upgraded directly. Please contact sales."),
+    FailedOperationCouponForFreeDomain = "FailedOperation.CouponForFreeDomain" => ("此优惠券只能被免费域名使用。", "This coupon can only be used for free domains."),
+    FailedOperationCouponNotSupported = "FailedOperation.CouponNotSupported" => ("您的帐户不满足使用此优惠券的条件。", "Your account does not meet the conditions for using this coupon."),
+    FailedOperationCouponTypeAlreadyUsed = "FailedOperation.CouponTypeAlreadyUsed" => ("域名已经使用过该类型的礼券了，不能重复使用。", "The domain has already used a coupon of this type and cannot use it again."),
+    FailedOperationDomainExists = "FailedOperation.DomainExists" => ("该域名已在您的列表中，无需重复添加。", "The domain is already in your list and does not need to be added again."),
+    FailedOperationDomainInEnterpriseMailAccount = "FailedOperation.DomainInEnterpriseMailAccount" => ("该域名属于企业邮用户", "The domain belongs to an enterprise mail user."),
+    FailedOperationDomainIsKeyDomain = "FailedOperation.DomainIsKeyDomain" => ("该域名为腾讯云 DNSPod 重点保护资源，为了避免误操作造成的业务影响，域名禁止自行操作删除。如果您确认需要删除域名，请先联系您的客户经理，我们将竭诚为您提供技术支持。", "This domain is a key resource protected by Tencent Cloud DNSPod and cannot be deleted by yourself to avoid business impact from misoperation. If you need to delete it, please contact your account manager."),
+    FailedOperationDomainIsLocked = "FailedOperation.DomainIsLocked" => ("锁定域名不能进行此操作。", "This operation is not allowed on a locked domain."),
+    FailedOperationDomainIsPersonalType = "FailedOperation.DomainIsPersonalType" => ("域名已升级为个人套餐，但目前位于企业账号，请与销售联系。", "The domain has been upgraded to a personal plan but is under an enterprise account. Please contact sales."),
+    FailedOperationDomainIsSpam = "FailedOperation.DomainIsSpam" => ("封禁域名不能进行此操作。", "This operation is not allowed on a banned domain."),
+    FailedOperationDomainIsVip = "FailedOperation.DomainIsVip" => ("VIP域名不能进行此操作。", "This operation is not allowed on a VIP domain."),
+    FailedOperationDomainNotInService = "FailedOperation.DomainNotInService" => ("当前域名还未使用 DNSPod 的解析服务，我们无法获取解析量数据。", "The domain is not using the DNSPod resolution service yet, so resolution statistics are unavailable."),
+    FailedOperationDomainOwnedByOtherUser = "FailedOperation.DomainOwnedByOtherUser" => ("该域名已被其他账号添加，可在域名列表中添加取回。", "The domain has been added by another account. You can retrieve it from the domain list."),
+    FailedOperationEipCheckFailed = "FailedOperation.EipCheckFailed" => ("校验公网 IP 发生异常。", "An exception occurred while verifying the public IP."),
+    FailedOperationFrequencyLimit = "FailedOperation.FrequencyLimit" => ("您操作过于频繁，请稍后重试", "You are operating too frequently. Please try again later."),
+    FailedOperationFunctionNotAllowedApply = "FailedOperation.FunctionNotAllowedApply" => ("此功能暂停申请，请稍候重试。", "Applications for this feature are suspended. Please try again later."),
+    FailedOperationGetWhoisFailed = "FailedOperation.GetWhoisFailed" => ("获取不到域名信息，可能域名非法或网络故障，请稍后再试。", "Failed to get the domain information. The domain may be invalid or there may be a network failure. Please try again later."),
+    FailedOperationInsufficientBalance = "FailedOperation.InsufficientBalance" => ("账户余额不足。", "Insufficient account balance."),
+    FailedOperationLoginAreaNotAllowed = "FailedOperation.LoginAreaNotAllowed" => ("帐号异地登录，请求被拒绝。", "The account is logged in from an unusual location and the request was denied."),
+    FailedOperationLoginFailed = "FailedOperation.LoginFailed" => ("登录失败，请检查账号和密码是否正确。", "Login failed. Please check whether the account and password are correct."),
+    FailedOperationLoginTimeout = "FailedOperation.LoginTimeout" => ("登录已经超时，请重新登录。", "The login has timed out. Please log in again."),
+    FailedOperationMobileNotVerified = "FailedOperation.MobileNotVerified" => ("用户手机没有通过验证。", "The user's mobile number has not been verified."),
+    FailedOperationNotBatchTaskOwner = "FailedOperation.NotBatchTaskOwner" => ("权限错误，您无法查看该任务的详情。", "Permission error: you cannot view the details of this task."),
+    FailedOperationNotDomainOwner = "FailedOperation.NotDomainOwner" => ("域名不在您的名下。", "The domain is not under your account."),
+    FailedOperationNotRealNamedUser = "FailedOperation.NotRealNamedUser" => ("未实名认证用户，请先完成实名认证再操作。", "Identity verification is required. Please complete it before proceeding."),
+    FailedOperationNotResourceOwner = "FailedOperation.NotResourceOwner" => ("您没有权限操作此资源。", "You do not have permission to operate on this resource."),
+    FailedOperationOrderCanNotPay = "FailedOperation.OrderCanNotPay" => ("您不能付款此订单。", "You cannot pay for this order."),
+    FailedOperationOrderHasPaid = "FailedOperation.OrderHasPaid" => ("此订单已经付过款。", "This order has already been paid."),
+    FailedOperationResourceNotBind = "FailedOperation.ResourceNotBind" => ("资源未绑定域名。", "The resource is not bound to a domain."),
+    FailedOperationTemporaryError = "FailedOperation.TemporaryError" => ("请求量统计数据暂时不可用，请稍后再试。", "Request statistics are temporarily unavailable. Please try again later."),
+    FailedOperationTransferToEnterpriseDenied = "FailedOperation.TransferToEnterpriseDenied" => ("不能转移到企业账号。", "Cannot transfer to an enterprise account."),
+    FailedOperationTransferToPersonDenied = "FailedOperation.TransferToPersonDenied" => ("不能转移到个人账号。", "Cannot transfer to a personal account."),
+    FailedOperationUnknowError = "FailedOperation.UnknowError" => ("操作未响应，请稍后重试。", "The operation did not respond. Please try again later."),
+    FailedOperationVerifyingBillExists = "FailedOperation.VerifyingBillExists" => ("域名已经提交过订单并且正在审核中，请稍候。", "An order for this domain has already been submitted and is under review. Please wait."),
+
+    InvalidParameterAccountIsBanned = "InvalidParameter.AccountIsBanned" => ("您的账号已被系统封禁，如果您有任何疑问请与我们联系。", "Your account has been banned by the system. Please contact us if you have any questions."),
+    InvalidParameterActionInvalid = "InvalidParameter.ActionInvalid" => ("无效的操作。", "Invalid operation."),
+    InvalidParameterActionSuccess = "InvalidParameter.ActionSuccess" => ("操作已经成功完成。", "The operation has completed successfully."),
+    InvalidParameterActivity = "InvalidParameter.Activity" => ("用户未实名。", "The user has not completed identity verification."),
+    InvalidParameterBatchDomainCreateActionError = "InvalidParameter.BatchDomainCreateActionError" => ("创建批量域名任务失败，原因：内部错误。", "Failed to create the batch domain task due to an internal error."),
+    InvalidParameterBatchRecordCreateActionError = "InvalidParameter.BatchRecordCreateActionError" => ("创建批量记录任务失败，原因：内部错误。", "Failed to create the batch record task due to an internal error."),
+    InvalidParameterBatchRecordModifyActionError = "InvalidParameter.BatchRecordModifyActionError" => ("批量修改记录任务失败，原因：内部错误。", "Failed to run the batch record modification task due to an internal error."),
+    InvalidParameterBatchRecordModifyActionInvalidValue = "InvalidParameter.BatchRecordModifyActionInvalidValue" => ("记录值无效。", "Invalid record value."),
+    InvalidParameterBatchRecordRemoveActionError = "InvalidParameter.BatchRecordRemoveActionError" => ("批量删除记录任务失败，原因：内部错误。", "Failed to run the batch record deletion task due to an internal error."),
+    InvalidParameterBatchRecordReplaceActionError = "InvalidParameter.BatchRecordReplaceActionError" => ("批量替换记录任务失败，原因：内部错误。", "Failed to run the batch record replacement task due to an internal error."),
+    InvalidParameterBatchTaskCountLimit = "InvalidParameter.BatchTaskCountLimit" => ("超过批量任务数上限。", "The number of batch tasks exceeds the limit."),
+    InvalidParameterBatchTaskNotExist = "InvalidParameter.BatchTaskNotExist" => ("任务不存在，无法获取任务详情。", "The task does not exist, so its details cannot be retrieved."),
+    InvalidParameterBillNumberInvalid = "InvalidParameter.BillNumberInvalid" => ("订单号码不正确。", "Incorrect order number."),
+    InvalidParameterBrowserNull = "InvalidParameter.BrowserNull" => ("浏览器字段为空。", "The browser field is empty."),
+    InvalidParameterCommon = "InvalidParameter.Common" => ("您操作过于频繁，请稍后重试。", "You are operating too frequently. Please try again later."),
+    InvalidParameterCustomMessage = "InvalidParameter.CustomMessage" => ("自定义错误信息。", "Custom error message."),
+    InvalidParameterDataExpired = "InvalidParameter.DataExpired" => ("数据过期,请重新提交。", "The data has expired. Please submit again."),
+    InvalidParameterDataInvalid = "InvalidParameter.DataInvalid" => ("data 无效。", "Invalid data."),
+    InvalidParameterDealTypeInvalid = "InvalidParameter.DealTypeInvalid" => ("订单类型无效。", "Invalid order type."),
+    InvalidParameterDnsDealDomainUpgraded = "InvalidParameter.DnsDealDomainUpgraded" => ("域名已升级至付费套餐，无法完成下单。", "The domain has been upgraded to a paid plan, so the order cannot be placed."),
+    InvalidParameterDnsDealLocked = "InvalidParameter.DnsDealLocked" => ("相关服务已有其他未完成的订单，请先将原订单支付或取消后，才可提交新的订单。", "The service has another unfinished order. Please pay for or cancel it before submitting a new one."),
+    InvalidParameterDnsInvalidDeal = "InvalidParameter.DnsInvalidDeal" => ("订单数据非法。", "Invalid order data."),
+    InvalidParameterDnssecAddCnameError = "InvalidParameter.DnssecAddCnameError" => ("该域名开启了 DNSSEC，不允许添加 @ 子域名 CNAME、显性 URL 或者隐性 URL 记录。", "DNSSEC is enabled for this domain, so CNAME, explicit URL or implicit URL records cannot be added for the @ subdomain."),
+    InvalidParameterDomainAliasExists = "InvalidParameter.DomainAliasExists" => ("别名已经存在。", "The alias already exists."),
+    InvalidParameterDomainAliasIdInvalid = "InvalidParameter.DomainAliasIdInvalid" => ("别名编号错误。", "Incorrect alias ID."),
+    InvalidParameterDomainIdInvalid = "InvalidParameter.DomainIdInvalid" => ("域名编号不正确。", "Incorrect domain ID."),
+    InvalidParameterDomainInBlackList = "InvalidParameter.DomainInBlackList" => ("该域名涉及违法违规黑名单，无法进行该操作", "The domain is in the blocklist for violating laws or regulations, so this operation cannot be performed."),
+    InvalidParameterDomainInEffectOrInvalidated = "InvalidParameter.DomainInEffectOrInvalidated" => ("不允许操作生效中或失效中的域名。", "Operations on domains that are taking effect or being invalidated are not allowed."),
+    InvalidParameterDomainInvalid = "InvalidParameter.DomainInvalid" => ("域名不正确，请输入主域名，如 dnspod.cn。", "Incorrect domain. Please enter a primary domain such as dnspod.cn."),
+    InvalidParameterDomainIsAliaser = "InvalidParameter.DomainIsAliaser" => ("此域名是其它域名的别名。", "This domain is an alias of another domain."),
+    InvalidParameterDomainIsModifyingDns = "InvalidParameter.DomainIsModifyingDns" => ("该域名已有同类型操作未完成，无法执行该操作。", "An operation of the same type on this domain is still in progress, so this operation cannot be performed."),
+    InvalidParameterDomainIsMyAlias = "InvalidParameter.DomainIsMyAlias" => ("此域名是自己域名的别名", "This domain is an alias of one of your own domains."),
+    InvalidParameterDomainIsNotlocked = "InvalidParameter.DomainIsNotlocked" => ("域名没有锁定。", "The domain is not locked."),
+    InvalidParameterDomainNotAllowedLock = "InvalidParameter.DomainNotAllowedLock" => ("暂停域名不支持锁定。", "Paused domains cannot be locked."),
+    InvalidParameterDomainNotAllowedModifyRecords = "InvalidParameter.DomainNotAllowedModifyRecords" => ("处于生效中/失效中的域名，不允许变更解析记录。", "Records cannot be modified for domains that are taking effect or being invalidated."),
+    InvalidParameterDomainNotBeian = "InvalidParameter.DomainNotBeian" => ("该域名未备案，无法添加 URL 记录。", "The domain has no ICP filing, so URL records cannot be added."),
+    InvalidParameterDomainNotReged = "InvalidParameter.DomainNotReged" => ("域名还没有注册，无法添加。", "The domain has not been registered and cannot be added."),
+    InvalidParameterDomainNotVip = "InvalidParameter.DomainNotVip" => ("原域名不是VIP域名，无法替换。", "The original domain is not a VIP domain and cannot be replaced."),
+    InvalidParameterDomainRecordExist = "InvalidParameter.DomainRecordExist" => ("记录已经存在，无需再次添加。", "The record already exists and does not need to be added again."),
+    InvalidParameterDomainSelfNoCopy = "InvalidParameter.DomainSelfNoCopy" => ("域名自己无需进行复制。", "A domain does not need to be copied to itself."),
+    InvalidParameterDomainTooLong = "InvalidParameter.DomainTooLong" => ("域名过长。", "The domain is too long."),
+    InvalidParameterDomainTypeInvalid = "InvalidParameter.DomainTypeInvalid" => ("域名类型错误。", "Incorrect domain type."),
+    InvalidParameterDomainsEmpty = "InvalidParameter.DomainsEmpty" => ("没有提交任何域名。", "No domains were submitted."),
+    InvalidParameterEmailInvalid = "InvalidParameter.EmailInvalid" => ("邮箱地址不正确。", "Incorrect email address."),
+    InvalidParameterEmailNotVerified = "InvalidParameter.EmailNotVerified" => ("抱歉，您的账户还没有通过邮箱验证。", "Sorry, your account has not passed email verification."),
+    InvalidParameterEmailOrQqInvalid = "InvalidParameter.EmailOrQqInvalid" => ("请输入合法的邮箱或者uin。", "Please enter a valid email address or UIN."),
+    InvalidParameterEmailSame = "InvalidParameter.EmailSame" => ("域名已经在该账号下。", "The domain is already under this account."),
+    InvalidParameterGoodsChildTypeInvalid = "InvalidParameter.GoodsChildTypeInvalid" => ("商品子类型无效。", "Invalid product subtype."),
+    InvalidParameterGoodsNumInvalid = "InvalidParameter.GoodsNumInvalid" => ("商品数量无效。", "Invalid product quantity."),
+    InvalidParameterGoodsTypeInvalid = "InvalidParameter.GoodsTypeInvalid" => ("商品类型无效。", "Invalid product type."),
+    InvalidParameterGradeNotCopy = "InvalidParameter.GradeNotCopy" => ("当前域名等级低于源域名的等级，无法进行复制。", "The grade of the current domain is lower than that of the source domain, so it cannot be copied."),
+    InvalidParameterGroupIdInvalid = "InvalidParameter.GroupIdInvalid" => ("分组编号不正确。", "Incorrect group ID."),
+    InvalidParameterGroupNameExists = "InvalidParameter.GroupNameExists" => ("同名分组已经存在。", "A group with the same name already exists."),
+    InvalidParameterGroupNameInvalid = "InvalidParameter.GroupNameInvalid" => ("分组名为1-17个字符。", "The group name must be 1-17 characters."),
+    InvalidParameterHasPendingApply = "InvalidParameter.HasPendingApply" => ("您已经提交过申请并且正在审核中，请耐心等候。", "You have already submitted an application and it is under review. Please wait."),
+    InvalidParameterIllegalNewDeal = "InvalidParameter.IllegalNewDeal" => ("该订单存在冲突或参数有误，无法完成支付，请重新购买。", "The order conflicts or has invalid parameters and cannot be paid. Please purchase again."),
+    InvalidParameterInnerTaskNotExist = "InvalidParameter.InnerTaskNotExist" => ("任务不存在。", "The task does not exist."),
+    InvalidParameterInvalidCoupon = "InvalidParameter.InvalidCoupon" => ("礼券代码无效。", "Invalid coupon code."),
+    InvalidParameterInvalidDealName = "InvalidParameter.InvalidDealName" => ("请输入正确的订单号。", "Please enter a correct order number."),
+    InvalidParameterInvalidSecretId = "InvalidParameter.InvalidSecretId" => ("无效密钥 ID。", "Invalid SecretId."),
+    InvalidParameterInvalidSignature = "InvalidParameter.InvalidSignature" => ("无效签名。", "Invalid signature."),
+    InvalidParameterInvalidTime = "InvalidParameter.InvalidTime" => ("无效的时间。", "Invalid time."),
+    InvalidParameterInvalidWeight = "InvalidParameter.InvalidWeight" => ("权重不合法。请输入0~100的整数。", "Invalid weight. Please enter an integer between 0 and 100."),
+    InvalidParameterIpsExceedLimit = "InvalidParameter.IpsExceedLimit" => ("ips 过长。", "Too many IPs."),
+    InvalidParameterJobGreaterThanLimit = "InvalidParameter.JobGreaterThanLimit" => ("单次任务数量超过上限。", "The number of tasks in a single job exceeds the limit."),
+    InvalidParameterLockDaysInvalid = "InvalidParameter.LockDaysInvalid" => ("锁定天数不正确。", "Incorrect number of lock days."),
+    InvalidParameterLoginTokenIdError = "InvalidParameter.LoginTokenIdError" => ("Token 的 ID 不正确。", "Incorrect token ID."),
+    InvalidParameterLoginTokenNotExists = "InvalidParameter.LoginTokenNotExists" => ("传入的 Token 不存在。", "The token does not exist."),
+    InvalidParameterLoginTokenValidateFailed = "InvalidParameter.LoginTokenValidateFailed" => ("Token 验证失败。", "Token verification failed."),
+    InvalidParameterMobileNotVerified = "InvalidParameter.MobileNotVerified" => ("抱歉，您的账户还没有通过手机验证。", "Sorry, your account has not passed mobile verification."),
+    InvalidParameterMxInvalid = "InvalidParameter.MxInvalid" => ("MX优先级不正确。", "Incorrect MX priority."),
+    InvalidParameterNewPackageTypeInvalid = "InvalidParameter.NewPackageTypeInvalid" => ("新套餐类型无效。", "Invalid new plan type."),
+    InvalidParameterOffsetInvalid = "InvalidParameter.OffsetInvalid" => ("分页起始数量错误。", "Incorrect pagination offset."),
+    InvalidParameterOpenidInvalid = "InvalidParameter.OpenidInvalid" => ("无效的openid。", "Invalid openid."),
+    InvalidParameterOperateFailed = "InvalidParameter.OperateFailed" => ("操作失败，请稍候再试。", "Operation failed. Please try again later."),
+    InvalidParameterOperationIsTooFrequent = "InvalidParameter.OperationIsTooFrequent" => ("当前操作过于频繁，请 1 分钟后重试。", "The operation is too frequent. Please try again in 1 minute."),
+    InvalidParameterOptypeNotSupported = "InvalidParameter.OptypeNotSupported" => ("不支持的操作类型。", "Unsupported operation type."),
+    InvalidParameterOtherAccountUnrealName = "InvalidParameter.OtherAccountUnrealName" => ("对方账号未实名认证，请先完成实名认证再操作。", "The other account has not completed identity verification. Please complete it before proceeding."),
+    InvalidParameterParamInvalid = "InvalidParameter.ParamInvalid" => ("param 格式错误。", "Invalid param format."),
+    InvalidParameterParamsIllegal = "InvalidParameter.ParamsIllegal" => ("参数不合法，请求被拒绝。", "Invalid parameters. The request was denied."),
+    InvalidParameterParamsMissing = "InvalidParameter.ParamsMissing" => ("参数错误。", "Parameter error."),
+    InvalidParameterPermissionDenied = "InvalidParameter.PermissionDenied" => ("鉴权失败。", "Authentication failed."),
+    InvalidParameterQcloudUinInvalid = "InvalidParameter.QcloudUinInvalid" => ("用户UIN无效。", "Invalid user UIN."),
+    InvalidParameterQuhuiTxtNotMatch = "InvalidParameter.QuhuiTxtNotMatch" => ("TXT 记录无法匹配，请确认记录值是否准确，并再次验证。", "The TXT record does not match. Please make sure the record value is correct and verify again."),
+    InvalidParameterQuhuiTxtRecordWait = "InvalidParameter.QuhuiTxtRecordWait" => ("TXT 记录未设置或暂未生效，请稍后重试。", "The TXT record is not set or has not taken effect yet. Please try again later."),
+    InvalidParameterRealNameUser = "InvalidParameter.RealNameUser" => ("已实名用户。", "The user has completed identity verification."),
+    InvalidParameterRecordIdInvalid = "InvalidParameter.RecordIdInvalid" => ("记录编号错误。", "Incorrect record ID."),
+    InvalidParameterRecordLineInvalid = "InvalidParameter.RecordLineInvalid" => ("记录线路不正确。", "Incorrect record line."),
+    InvalidParameterRecordTypeInvalid = "InvalidParameter.RecordTypeInvalid" => ("记录类型不正确。", "Incorrect record type."),
+    InvalidParameterRecordValueInvalid = "InvalidParameter.RecordValueInvalid" => ("记录的值不正确。", "Incorrect record value."),
+    InvalidParameterRecordValueLengthInvalid = "InvalidParameter.RecordValueLengthInvalid" => ("解析记录值过长。", "The record value is too long."),
+    InvalidParameterRecordsEmpty = "InvalidParameter.RecordsEmpty" => ("没有提交任何记录。", "No records were submitted."),
+    InvalidParameterRemarkLengthExceeded = "InvalidParameter.RemarkLengthExceeded" => ("备注信息超出字符限制。", "The remark exceeds the character limit."),
+    InvalidParameterRemarkTooLong = "InvalidParameter.RemarkTooLong" => ("备注过长（不能超过200个字）。", "The remark is too long (up to 200 characters)."),
+    InvalidParameterRequestIpLimited = "InvalidParameter.RequestIpLimited" => ("您的IP非法，请求被拒绝。", "Your IP is invalid and the request was denied."),
+    InvalidParameterResultMoreThan500 = "InvalidParameter.ResultMoreThan500" => ("搜索结果大于500条，请增加关键字。", "More than 500 results were found. Please add more keywords."),
+    InvalidParameterSharedUsersUnrealName = "InvalidParameter.SharedUsersUnrealName" => ("共享用户中包含未实名认证用户。", "The shared users include users who have not completed identity verification."),
+    InvalidParameterStatusCodeInvalid = "InvalidParameter.StatusCodeInvalid" => ("状态代码不正确。", "Incorrect status code."),
+    InvalidParameterSubdomainInvalid = "InvalidParameter.SubdomainInvalid" => ("子域名不正确。", "Incorrect subdomain."),
+    InvalidParameterTaskNotCompleted = "InvalidParameter.TaskNotCompleted" => ("任务未完成。", "The task is not completed."),
+    InvalidParameterTimeSpanInvalid = "InvalidParameter.TimeSpanInvalid" => ("时长无效。", "Invalid duration."),
+    InvalidParameterTimestampExpired = "InvalidParameter.TimestampExpired" => ("时间戳已过期。", "The timestamp has expired."),
+    InvalidParameterTooManyInvalidDomains = "InvalidParameter.TooManyInvalidDomains" => ("当前帐号下的无效域名过多，暂时无法使用该功能。请将已有域名的 DNS 服务器正确指向 DNSPod 之后，再尝试添加。", "There are too many invalid domains under the current account, so this feature is temporarily unavailable. Please point the DNS servers of your existing domains to DNSPod and try again."),
+    InvalidParameterToolsDomainInvalid = "InvalidParameter.ToolsDomainInvalid" => ("域名无效。", "Invalid domain."),
+    InvalidParameterUnLockCodeExpired = "InvalidParameter.UnLockCodeExpired" => ("解锁代码已失效。", "The unlock code has expired."),
+    InvalidParameterUnLockCodeInvalid = "InvalidParameter.UnLockCodeInvalid" => ("解锁代码不正确。", "Incorrect unlock code."),
+    InvalidParameterUnrealNameUser = "InvalidParameter.UnrealNameUser" => ("未实名认证用户，请先完成实名认证再操作。", "Identity verification is required. Please complete it before proceeding."),
+    InvalidParameterUrlValueIllegal = "InvalidParameter.UrlValueIllegal" => ("很抱歉，您要添加的URL的内容不符合DNSPod解析服务条款，URL添加/启用失败，如需帮助请联系技术支持。", "Sorry, the content of the URL does not comply with the DNSPod service terms, so it cannot be added or enabled. Please contact technical support if you need help."),
+    InvalidParameterUserAlreadyLocked = "InvalidParameter.UserAlreadyLocked" => ("账号已经被锁定。", "The account has already been locked."),
+    InvalidParameterUserAreaInvalid = "InvalidParameter.UserAreaInvalid" => ("对方账户非国内站用户。", "The other account is not a China site user."),
+    InvalidParameterUserNotExists = "InvalidParameter.UserNotExists" => ("用户不存在。", "The user does not exist."),
+    InvalidParameterUuidInvalid = "InvalidParameter.UuidInvalid" => ("无效的uuid。", "Invalid uuid."),
+
+    InvalidParameterValueDomainGradeInvalid = "InvalidParameterValue.DomainGradeInvalid" => ("域名等级不正确。", "Incorrect domain grade."),
+    InvalidParameterValueDomainNotExists = "InvalidParameterValue.DomainNotExists" => ("当前域名有误，请返回重新操作。", "The current domain is incorrect. Please go back and try again."),
+    InvalidParameterValueLimitInvalid = "InvalidParameterValue.LimitInvalid" => ("分页长度数量错误。", "Incorrect pagination limit."),
+    InvalidParameterValueUpgradeTermInvalid = "InvalidParameterValue.UpgradeTermInvalid" => ("升级年限不正确。", "Incorrect upgrade term."),
+    InvalidParameterValueUserIdInvalid = "InvalidParameterValue.UserIdInvalid" => ("用户编号不正确。", "Incorrect user ID."),
+
+    LimitExceededAAAACountLimit = "LimitExceeded.AAAACountLimit" => ("AAAA记录数量超出限制。", "The number of AAAA records exceeds the limit."),
+    LimitExceededAtNsRecordLimit = "LimitExceeded.AtNsRecordLimit" => ("@的NS记录只能设置为默认线路。", "NS records for @ can only use the default line."),
+    LimitExceededDomainAliasCountExceeded = "LimitExceeded.DomainAliasCountExceeded" => ("别名数量已经达到限制。", "The number of aliases has reached the limit."),
+    LimitExceededDomainAliasNumberLimit = "LimitExceeded.DomainAliasNumberLimit" => ("当前绑定别名数量已达到限制。", "The number of bound aliases has reached the limit."),
+    LimitExceededFailedLoginLimitExceeded = "LimitExceeded.FailedLoginLimitExceeded" => ("登录失败次数过多已被系统封禁。", "The account has been banned due to too many failed logins."),
+    LimitExceededGroupNumberLimit = "LimitExceeded.GroupNumberLimit" => ("已经达到最大分组数量限制。", "The maximum number of groups has been reached."),
+    LimitExceededHiddenUrlExceeded = "LimitExceeded.HiddenUrlExceeded" => ("该域名使用的套餐不支持隐性URL转发或数量已达上限，如需要使用，请去商城购买。", "The plan of this domain does not support implicit URL forwarding or the limit has been reached. Please purchase it in the store if needed."),
+    LimitExceededNsCountLimit = "LimitExceeded.NsCountLimit" => ("NS记录数量超出限制。", "The number of NS records exceeds the limit."),
+    LimitExceededOffsetExceeded = "LimitExceeded.OffsetExceeded" => ("分页起始数量过大。", "The pagination offset is too large."),
+    LimitExceededRecordTtlLimit = "LimitExceeded.RecordTtlLimit" => ("记录的TTL值超出了限制。", "The TTL value of the record exceeds the limit."),
+    LimitExceededSrvCountLimit = "LimitExceeded.SrvCountLimit" => ("SRV记录数量超出限制。", "The number of SRV records exceeds the limit."),
+    LimitExceededSubdomainLevelLimit = "LimitExceeded.SubdomainLevelLimit" => ("子域名级数超出限制。", "The subdomain level exceeds the limit."),
+    LimitExceededSubdomainRollLimit = "LimitExceeded.SubdomainRollLimit" => ("子域名负载均衡数量超出限制。", "The number of load-balanced records for the subdomain exceeds the limit."),
+    LimitExceededSubdomainWcardLimit = "LimitExceeded.SubdomainWcardLimit" => ("泛解析级数超出限制。", "The wildcard level exceeds the limit."),
+    LimitExceededUrlCountLimit = "LimitExceeded.UrlCountLimit" => ("该域名的显性URL转发数量已达上限，如需继续使用，请去商城购买。", "The number of explicit URL forwarding records for this domain has reached the limit. Please purchase more in the store if needed."),
+
+    OperationDenied = "OperationDenied" => ("操作被拒绝。", "The operation was denied."),
+    OperationDeniedAccessDenied = "OperationDenied.AccessDenied" => ("您没有权限执行此操作。", "You do not have permission to perform this operation."),
+    OperationDeniedAgentDenied = "OperationDenied.AgentDenied" => ("代理不能使用此功能。", "Agents cannot use this feature."),
+    OperationDeniedAgentSubordinateDenied = "OperationDenied.AgentSubordinateDenied" => ("代理名下的用户不能使用此功能。", "Users under an agent cannot use this feature."),
+    OperationDeniedCancelBillNotAllowed = "OperationDenied.CancelBillNotAllowed" => ("此订单不能取消。", "This order cannot be canceled."),
+    OperationDeniedDeleteUsingRecordLineNotAllowed = "OperationDenied.DeleteUsingRecordLineNotAllowed" => ("该线路正在使用当中，不能删除。", "The line is in use and cannot be deleted."),
+    OperationDeniedDomainOwnerAllowedOnly = "OperationDenied.DomainOwnerAllowedOnly" => ("仅域名所有者可进行此操作。", "Only the domain owner can perform this operation."),
+    OperationDeniedIPInBlacklistNotAllowed = "OperationDenied.IPInBlacklistNotAllowed" => ("抱歉，不允许添加黑名单中的IP。", "Sorry, IPs in the blocklist cannot be added."),
+    OperationDeniedMonitorCallbackNotEnabled = "OperationDenied.MonitorCallbackNotEnabled" => ("抱歉，您的域名等级不支持D监控通知回调功能。", "Sorry, your domain grade does not support D-Monitor notification callbacks."),
+    OperationDeniedNoPermissionToOperateDomain = "OperationDenied.NoPermissionToOperateDomain" => ("当前域名无权限，请返回域名列表。", "You do not have permission for this domain. Please go back to the domain list."),
+    OperationDeniedNotAdmin = "OperationDenied.NotAdmin" => ("您不是管理用户。", "You are not an admin user."),
+    OperationDeniedNotAgent = "OperationDenied.NotAgent" => ("您不是代理用户。", "You are not an agent user."),
+    OperationDeniedNotGrantedByOwner = "OperationDenied.NotGrantedByOwner" => ("您还没有获取到授权，无法执行此操作。", "You have not been authorized to perform this operation."),
+    OperationDeniedNotManagedUser = "OperationDenied.NotManagedUser" => ("不是您名下用户。", "The user is not under your account."),
+    OperationDeniedNotOrderOwner = "OperationDenied.NotOrderOwner" => ("您没有权限操作此订单。", "You do not have permission to operate on this order."),
+    OperationDeniedNotResourceOwner = "OperationDenied.NotResourceOwner" => ("您没有权限操作此资源。", "You do not have permission to operate on this resource."),
+    OperationDeniedPersonalCouponNotAllowed = "OperationDenied.PersonalCouponNotAllowed" => ("此礼券为个人礼券，请使用企业礼券。", "This is a personal coupon. Please use an enterprise coupon."),
+    OperationDeniedPostRequestAcceptOnly = "OperationDenied.PostRequestAcceptOnly" => ("只支持 POST 方法提交数据。", "Only the POST method is supported for submitting data."),
+    OperationDeniedResourceNotAllowRenew = "OperationDenied.ResourceNotAllowRenew" => ("该资源不允许续费。", "This resource cannot be renewed."),
+    OperationDeniedVipDomainAllowed = "OperationDenied.VipDomainAllowed" => ("企业用户的域名需要升级到VIP才能解析。", "Domains of enterprise users must be upgraded to VIP to be resolved."),
+
+    RequestLimitExceededBatchTaskLimit = "RequestLimitExceeded.BatchTaskLimit" => ("您的IP添加了过多任务，请稍后重试。", "Your IP has added too many tasks. Please try again later."),
+    RequestLimitExceededCreateDomainLimit = "RequestLimitExceeded.CreateDomainLimit" => ("您的帐号在短时间内添加了大量的域名，请控制添加频率。", "Your account has added a large number of domains in a short time. Please reduce the frequency."),
+    RequestLimitExceededRequestLimitExceeded = "RequestLimitExceeded.RequestLimitExceeded" => ("API请求次数超出限制。", "The number of API requests exceeds the limit."),
+
+    ResourceNotFoundNoDataOfDomain = "ResourceNotFound.NoDataOfDomain" => ("域名列表为空。", "The domain list is empty."),
+    ResourceNotFoundNoDataOfDomainAlias = "ResourceNotFound.NoDataOfDomainAlias" => ("没有域名别名。", "There are no domain aliases."),
+    ResourceNotFoundNoDataOfGift = "ResourceNotFound.NoDataOfGift" => ("还没有任何礼券。", "There are no coupons yet."),
+    ResourceNotFoundNoDataOfRecord = "ResourceNotFound.NoDataOfRecord" => ("记录列表为空。", "The record list is empty."),
+
+    ResourcesSoldOut = "ResourcesSoldOut" => ("资源售罄。", "The resources are sold out."),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let code = ErrorCode::from("AuthFailure.SignatureExpire");
+        assert_eq!(code, ErrorCode::AuthFailureSignatureExpire);
+        assert_eq!(code.code(), "AuthFailure.SignatureExpire");
+        assert_eq!(code.category(), ErrorCategory::AuthFailure);
+        assert_eq!(code.to_string(), "AuthFailure.SignatureExpire");
+
+        let code: ErrorCode = "InvalidParameter.NewCode".parse().unwrap();
+        assert_eq!(code, ErrorCode::Unknown("InvalidParameter.NewCode".into()));
+        assert_eq!(code.code(), "InvalidParameter.NewCode");
+        assert_eq!(code.category(), ErrorCategory::InvalidParameter);
+        assert_eq!(code.to_description(), "未知错误");
+        assert_eq!(code.description(Language::EnUS), "Unknown error");
+        assert_eq!(
+            ErrorCode::InternalError.category(),
+            ErrorCategory::InternalError
+        );
+        let category = ErrorCode::from("NewCategory.Code").category();
+        assert_eq!(category, ErrorCategory::Other("NewCategory".into()));
+        assert_eq!(category.to_string(), "NewCategory");
+    }
+
+    #[test]
+    fn descriptions() {
+        let code = ErrorCode::ResourceNotFoundNoDataOfRecord;
+        assert_eq!(code.to_description(), "记录列表为空。");
+        assert_eq!(code.description(Language::ZhCN), "记录列表为空。");
+        assert_eq!(
+            code.description(Language::EnUS),
+            "The record list is empty."
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let code: ErrorCode = serde_json::from_str(r#""ResourceNotFound.NoDataOfRecord""#).unwrap();
+        assert_eq!(code, ErrorCode::ResourceNotFoundNoDataOfRecord);
+        assert_eq!(
+            serde_json::to_string(&code).unwrap(),
+            r#""ResourceNotFound.NoDataOfRecord""#
+        );
+    }
+
+    #[test]
+    fn kinds() {
+        assert!(ErrorCode::RequestLimitExceededUinLimitExceeded.is_retryable());
+        assert!(ErrorCode::InternalError.is_retryable());
+        assert!(ErrorCode::from("RequestLimitExceeded.NewLimit").is_retryable());
+        assert!(!ErrorCode::InvalidParameterRecordValueInvalid.is_retryable());
+
+        assert!(ErrorCode::AuthFailureSecretIdNotFound.is_auth());
+        assert!(!ErrorCode::ResourceNotFoundNoDataOfRecord.is_auth());
+
+        assert!(ErrorCode::ResourceNotFoundNoDataOfRecord.is_not_found());
+        assert!(ErrorCode::InvalidParameterValueDomainNotExists.is_not_found());
+        assert!(!ErrorCode::FailedOperationDomainExists.is_not_found());
+        assert!(!ErrorCode::InvalidParameterRecordIdInvalid.is_not_found());
+    }
+}
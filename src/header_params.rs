@@ -7,8 +7,24 @@ use chrono::DateTime;
 use chrono::Utc;
 use serde::Serialize;
 
+use crate::error::{Error, Result};
+use crate::transport::Method;
 use crate::utils::datetime_to_timestamp_string;
 
+/// [`ExtractCommonParams::signed_headers`](crate::ExtractCommonParams::signed_headers) 的签名选项, 未指定的使用默认值
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SignOptions<'a> {
+    /// 服务器返回错误信息的语言 (`X-TC-Language`)
+    pub language: Language,
+    /// 默认为请求的 [`method`](crate::ExtractCommonParams::method). GET 请求的参数在查询字符串中, 请求正文为空
+    pub method: Option<Method>,
+    /// 实际发送的 url, 默认为请求的 [`url`](crate::ExtractCommonParams::url).
+    /// 签名中的 `host` 取自 `url`, 比如国际站、代理或本地的测试服务器
+    pub url: Option<&'a str>,
+    /// 签名时间, 默认为当前时间
+    pub datetime: Option<DateTime<Utc>>,
+}
+
 /// Headers
 #[derive(Debug, Serialize)]
 pub struct HeaderParams {
@@ -31,17 +47,21 @@ pub struct HeaderParams {
     pub authorization: String,
     /// X-TC-Language
     /// en-US/zh-CN
-    #[serde(rename = "Language")]
+    #[serde(rename = "X-TC-Language")]
     pub language: Language,
     #[serde(rename = "Content-Type")]
     pub content_type: ContentType,
+    /// X-TC-Token
+    /// 临时密钥的 Token, 长期密钥不需要
+    #[serde(rename = "X-TC-Token", skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
 }
 
-impl From<HeaderParams> for HashMap<String, String> {
-    fn from(value: HeaderParams) -> Self {
-        let value = serde_json::to_value(value)
-            .expect("error: HeaderParams to HashMap failed (serde_json::to_value)");
-        serde_json::from_value(value)
-            .expect("error: HeaderParams to HashMap failed (serde_json::from_value)")
+impl TryFrom<HeaderParams> for HashMap<String, String> {
+    type Error = Error;
+
+    fn try_from(value: HeaderParams) -> Result<Self> {
+        let value = serde_json::to_value(value).map_err(Error::Serialize)?;
+        serde_json::from_value(value).map_err(Error::Serialize)
     }
 }
@@ -1,19 +1,27 @@
 #![doc = include_str!("../README.md")]
+// `custom_meta_struct!` 逐个 token 展开 action.rs 中的全部 Action
+#![recursion_limit = "256"]
 
 use std::collections::HashMap;
 
 use chrono::Utc;
+use credential::Credential;
 use data_types::*;
 use header_params::HeaderParams;
-use signature::calculate_authorization;
+use transport::Method;
 
 pub mod action;
+pub mod client;
+pub mod clock;
 pub mod consts;
+pub mod credential;
 pub mod data_types;
+pub mod error;
 pub mod error_code;
 pub mod header_params;
 pub mod response;
 pub mod signature;
+pub mod transport;
 mod macros;
 pub mod utils;
 
@@ -22,27 +30,108 @@ pub mod utils;
 // #[macro_use]
 pub use serde;
 pub use serde_json;
+pub use chrono;
+
+pub use error::{Error, Result};
+pub use header_params::SignOptions;
 
 pub mod prelude {
     pub use super::action::*;
     pub use super::ExtractCommonParams;
+    pub use super::ExtractOutput;
+    pub use super::client::AsyncClient;
+    pub use super::client::Client;
 }
 
 pub trait ExtractCommonParams {
     fn action(&self) -> &'static str;
-    fn body(&self) -> Vec<u8>;
+    /// 请求正文, 即序列化后的请求参数, 无法序列化时返回 [`Error::Serialize`]
+    fn try_body(&self) -> Result<Vec<u8>>;
+    /// 同 [`ExtractCommonParams::try_body`]
+    ///
+    /// # Panics
+    ///
+    /// 请求参数无法序列化时 panic
+    #[deprecated(note = "请求参数无法序列化时会 panic, 请使用 `try_body`")]
+    fn body(&self) -> Vec<u8> { self.try_body().expect("failed to serialize request") }
     fn url(&self) -> &'static str { consts::DNSPOD_URL }
     fn version(&self) -> Version { Default::default() }
     fn region(&self) -> Option<Region> { None }
+    /// 默认为 POST, 可以通过 `@[method = Method::GET]` 覆盖
+    fn method(&self) -> Method { Method::POST }
+    /// 重复发送是否安全, 默认为 true. 可以通过 `@[idempotent = false]` 覆盖, 这样的请求失败后默认不会重试
+    fn idempotent(&self) -> bool { true }
+    /// 发送前检查参数组合, 如 MX 记录必须有优先级. 默认不检查, 可以通过 `@[validate = ...]` 覆盖
+    fn validate(&self) -> Result<()> { Ok(()) }
+    /// GET 请求的查询字符串, 由 [`ExtractCommonParams::try_body`] 中的参数按 RFC3986 编码并排序
+    ///
+    /// 请求正文不是 json 时返回 [`Error::Serialize`]
+    fn try_query(&self) -> Result<String> { utils::canonical_query_string(&self.try_body()?) }
+    /// 同 [`ExtractCommonParams::try_query`]
+    ///
+    /// # Panics
+    ///
+    /// 请求参数无法序列化时 panic
+    #[deprecated(note = "请求参数无法序列化时会 panic, 请使用 `try_query`")]
+    fn query(&self) -> String { self.try_query().expect("failed to serialize request") }
 
+    /// 签名并生成请求头
+    ///
+    /// # Panics
+    ///
+    /// 请求参数无法序列化时 panic
+    #[deprecated(note = "请求参数无法序列化时会 panic, 请使用 `try_headers` 或 `signed_headers`")]
     fn headers(
         &self,
         secret_id: &impl AsRef<str>,
         secret_key: &impl AsRef<str>,
     ) -> HashMap<String, String> {
-        let datetime = Utc::now();
-        // let timestamp = datetime.timestamp() as u64;
-        // let date = datetime.date_naive().to_string();
+        self.try_headers(secret_id, secret_key)
+            .expect("failed to serialize request")
+    }
+
+    /// 同 [`ExtractCommonParams::headers`], 请求参数无法序列化时返回 [`Error::Serialize`]
+    fn try_headers(
+        &self,
+        secret_id: &impl AsRef<str>,
+        secret_key: &impl AsRef<str>,
+    ) -> Result<HashMap<String, String>> {
+        let credential = Credential::new(secret_id.as_ref(), secret_key.as_ref());
+        self.signed_headers(&credential, &SignOptions::default())
+    }
+
+    /// 用 `credential` 签名并生成请求头, 发送方式、时间和语言由 `options` 指定
+    ///
+    /// 临时密钥的 Token 会作为 `X-TC-Token` 发送并参与签名. 指定 `datetime` 时结果是确定的, 可用于测试或根据日志复现签名
+    ///
+    /// ```
+    /// use dnspod_lib::chrono::{TimeZone, Utc};
+    /// use dnspod_lib::credential::Credential;
+    /// use dnspod_lib::prelude::*;
+    /// use dnspod_lib::SignOptions;
+    ///
+    /// let request = DeleteRecord { Domain: "example.com".into(), RecordId: 1 };
+    /// let credential = Credential::new("id", "key");
+    /// let options = SignOptions {
+    ///     datetime: Some(Utc.timestamp_opt(1551113065, 0).unwrap()),
+    ///     ..Default::default()
+    /// };
+    /// let headers = request.signed_headers(&credential, &options).unwrap();
+    /// assert_eq!(headers["X-TC-Timestamp"], "1551113065");
+    /// assert_eq!(headers, request.signed_headers(&credential, &options).unwrap());
+    /// ```
+    fn signed_headers(
+        &self,
+        credential: &Credential,
+        options: &SignOptions,
+    ) -> Result<HashMap<String, String>> {
+        let method = options.method.unwrap_or_else(|| self.method());
+        let url = options.url.unwrap_or_else(|| self.url());
+        let datetime = options.datetime.unwrap_or_else(Utc::now);
+        let (content_type, payload, query) = match method {
+            Method::GET => (ContentType::FormUrlEncoded, vec![], self.try_query()?),
+            Method::POST => (ContentType::JSON, self.try_body()?, String::new()),
+        };
 
         let mut hp = HeaderParams {
             action: self.action(),
@@ -50,14 +139,23 @@ pub trait ExtractCommonParams {
             region: self.region(),
             datetime,
             authorization: "".into(),
-            language: Language::EnUS,
-            content_type: ContentType::JSON,
+            language: options.language,
+            content_type,
+            token: credential.token.clone(),
         };
 
-        let body = self.body();
-        hp.authorization =
-            calculate_authorization(&body, &hp, secret_id.as_ref(), secret_key.as_ref());
+        hp.authorization = signature::signer(consts::SERVICE, utils::url_host(url), &hp)
+            .method(method)
+            .query(query)
+            .authorization(&payload, &credential.secret_id, &credential.secret_key, datetime);
 
-        hp.into()
+        hp.try_into()
     }
 }
+
+/// 请求对应的返回结果类型, 即服务器返回的 `Response` 字段
+///
+/// 通过 `define_action_list!` 定义的请求可以用 `@[response = ...]` 指定, 默认为 `serde_json::Value`
+pub trait ExtractOutput: ExtractCommonParams {
+    type Output: serde::de::DeserializeOwned;
+}
@@ -330,6 +330,7 @@ macro_rules! finally {
 
 
 #[cfg(test)]
+#[allow(dead_code)]
 mod tests {
     custom_meta_struct! {}
     custom_meta_struct! {
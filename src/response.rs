@@ -24,12 +24,6 @@ crate::custom_meta_struct! {
         #[derive(Debug, Clone, crate::serde::Serialize, crate::serde::Deserialize)]
     ),
 
-    /// 返回结果
-    /// <https://cloud.tencent.com/document/api/1427/56191>
-    pub struct Response {
-        pub Response: InnerResponse,
-    }
-
     pub struct InnerResponse {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub Error: Option<Error>,
@@ -61,7 +55,7 @@ crate::custom_meta_struct! {
     }
 
     pub struct Error {
-        #[serde(flatten)]
+        #[serde(rename = "Code")]
         pub code: ErrorCode,
         pub Message: String,
     }
@@ -308,34 +302,117 @@ crate::custom_meta_struct! {
     ///被如下接口引用：[DescribeRecordLineList](super::action::DescribeRecordLineList)
     pub struct LineInfo {
         /// 线路名称 示例值：电信
-        Name: String,
+        pub Name: String,
         /// 线路ID 示例值：10=0
-        LineId: String,
+        pub LineId: String,
     }
 
     /// 线路分组信息
     /// 被如下接口引用：[DescribeRecordLineList](super::action::DescribeRecordLineList)
     pub struct LineGroupInfo {
         /// 线路分组ID 示例值：15=0
-        LineId: String,	
+        pub LineId: String,	
         /// 线路分组名称 示例值：华北
-        Name: String,
+        pub Name: String,
         /// 分组类型 示例值：system
-        Type: String,	
+        pub Type: String,	
         /// 线路分组包含的线路列表
-        LineList: Vec<String>,
+        pub LineList: Vec<String>,
     }
 
     /// 域名信息（创建域名时返回） 被如下接口引用：[CreateDomain](super::action::CreateDomain)
     pub struct DomainCreateInfo {
         /// 域名ID
-        Id: Integer,
+        pub Id: Integer,
         /// 域名
-        Domain: String,
+        pub Domain: String,
         /// 域名的punycode 示例值：dnspod.cn
-        Punycode: String,
+        pub Punycode: String,
         /// 域名的NS列表 示例值：["source.dnspod.net","low.dnspod.net"]
-        GradeNsList: Vec<String>,
+        pub GradeNsList: Vec<String>,
+    }
+
+    /// [DescribeDomainList](super::action::DescribeDomainList) 的返回结果
+    pub struct DomainListResponse {
+        /// 列表页统计信息
+        pub DomainCountInfo: DomainCountInfo,
+        /// 域名列表
+        pub DomainList: Vec<DomainListItem>,
+        /// 唯一请求 ID，每次请求都会返回。定位问题时需要提供该次请求的 RequestId
+        pub RequestId: String,
+    }
+
+    /// [CreateDomain](super::action::CreateDomain) 的返回结果
+    pub struct CreateDomainResponse {
+        /// 域名信息
+        pub DomainInfo: DomainCreateInfo,
+        /// 唯一请求 ID，每次请求都会返回。定位问题时需要提供该次请求的 RequestId
+        pub RequestId: String,
+    }
+
+    /// 只有 RequestId 的返回结果, 如 [DeleteDomain](super::action::DeleteDomain), [DeleteRecord](super::action::DeleteRecord)
+    pub struct EmptyResponse {
+        /// 唯一请求 ID，每次请求都会返回。定位问题时需要提供该次请求的 RequestId
+        pub RequestId: String,
     }
+
+    /// 返回记录 ID 的结果, 如 [CreateRecord](super::action::CreateRecord), [ModifyRecord](super::action::ModifyRecord)
+    pub struct RecordIdResponse {
+        /// 记录ID
+        /// 示例值：162
+        pub RecordId: Integer,
+        /// 唯一请求 ID，每次请求都会返回。定位问题时需要提供该次请求的 RequestId
+        pub RequestId: String,
+    }
+
+    /// [DescribeRecordList](super::action::DescribeRecordList) 的返回结果
+    pub struct RecordListResponse {
+        /// 记录的数量统计信息
+        pub RecordCountInfo: RecordCountInfo,
+        /// 获取的记录列表
+        pub RecordList: Vec<RecordListItem>,
+        /// 唯一请求 ID，每次请求都会返回。定位问题时需要提供该次请求的 RequestId
+        pub RequestId: String,
+    }
+
+    /// [DescribeRecord](super::action::DescribeRecord) 的返回结果
+    pub struct RecordInfoResponse {
+        /// 记录信息
+        pub RecordInfo: RecordInfo,
+        /// 唯一请求 ID，每次请求都会返回。定位问题时需要提供该次请求的 RequestId
+        pub RequestId: String,
+    }
+
+    /// [DescribeRecordType](super::action::DescribeRecordType) 的返回结果
+    pub struct RecordTypeResponse {
+        /// 记录类型列表
+        /// 示例值：["A","CNAME","MX"]
+        pub TypeList: Vec<String>,
+        /// 唯一请求 ID，每次请求都会返回。定位问题时需要提供该次请求的 RequestId
+        pub RequestId: String,
+    }
+
+    /// [DescribeRecordLineList](super::action::DescribeRecordLineList) 的返回结果
+    pub struct RecordLineListResponse {
+        /// 线路列表
+        pub LineList: Vec<LineInfo>,
+        /// 线路分组列表
+        pub LineGroupList: Vec<LineGroupInfo>,
+        /// 唯一请求 ID，每次请求都会返回。定位问题时需要提供该次请求的 RequestId
+        pub RequestId: String,
+    }
+}
+
+/// 返回结果, `T` 为 `Response` 字段的具体类型, 默认为 [`InnerResponse`]
+/// <https://cloud.tencent.com/document/api/1427/56191>
+#[derive(Debug, Clone, crate::serde::Serialize, crate::serde::Deserialize)]
+pub struct Response<T = InnerResponse> {
+    pub Response: T,
 }
 
+impl Response {
+    /// 唯一请求 ID，每次请求都会返回。定位问题时需要提供该次请求的 RequestId
+    pub fn request_id(&self) -> &str {
+        &self.Response.RequestId
+    }
+}
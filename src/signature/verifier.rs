@@ -0,0 +1,392 @@
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::{Duration, TimeZone, Utc};
+
+use super::Tc3Signer;
+use crate::clock::{Clock, SystemClock};
+use crate::consts::{ALGORITHM, TERMINATOR};
+use crate::credential::Credential;
+use crate::transport::HttpRequest;
+use crate::utils::url_host;
+
+/// 校验失败的原因, 与服务器返回的 `AuthFailure.*` 错误码一一对应
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    /// `AuthFailure.InvalidAuthorization` 请求头部的 Authorization 不符合腾讯云标准
+    InvalidAuthorization(String),
+    /// `AuthFailure.SecretIdNotFound` 密钥不存在
+    SecretIdNotFound(String),
+    /// `AuthFailure.SignatureExpire` Timestamp 和服务器时间相差超过允许的范围
+    SignatureExpire { timestamp: i64, now: i64 },
+    /// `AuthFailure.SignatureFailure` 签名错误
+    SignatureFailure(String),
+    /// `AuthFailure.TokenFailure` 临时密钥的 Token 错误
+    TokenFailure,
+}
+
+impl VerifyError {
+    /// 对应的错误码
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidAuthorization(_) => "AuthFailure.InvalidAuthorization",
+            Self::SecretIdNotFound(_) => "AuthFailure.SecretIdNotFound",
+            Self::SignatureExpire { .. } => "AuthFailure.SignatureExpire",
+            Self::SignatureFailure(_) => "AuthFailure.SignatureFailure",
+            Self::TokenFailure => "AuthFailure.TokenFailure",
+        }
+    }
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = self.code();
+        match self {
+            Self::InvalidAuthorization(reason) => write!(f, "{code}: {reason}"),
+            Self::SecretIdNotFound(secret_id) => write!(f, "{code}: {secret_id}"),
+            Self::SignatureExpire { timestamp, now } => {
+                write!(f, "{code}: timestamp {timestamp}, server time {now}")
+            }
+            Self::SignatureFailure(reason) => write!(f, "{code}: {reason}"),
+            Self::TokenFailure => write!(f, "{code}"),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+/// 解析后的 Authorization 头部
+///
+/// ```txt
+/// TC3-HMAC-SHA256 Credential=AKIDz8krbsJ5yKBZQpn74WFkmLPx3*******/2019-02-25/cvm/tc3_request, SignedHeaders=content-type;host;x-tc-action, Signature=be4f67d3...
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tc3Authorization {
+    pub secret_id: String,
+    /// 凭证范围中的日期, 如 `2019-02-25`
+    pub date: String,
+    pub service: String,
+    /// 小写的头部名称
+    pub signed_headers: Vec<String>,
+    pub signature: String,
+}
+
+impl Tc3Authorization {
+    pub fn parse(authorization: &str) -> Result<Self, VerifyError> {
+        let invalid = |reason: &str| VerifyError::InvalidAuthorization(reason.into());
+
+        let (algorithm, rest) = authorization
+            .trim()
+            .split_once(' ')
+            .ok_or(invalid("missing algorithm"))?;
+        if algorithm != ALGORITHM {
+            return Err(invalid("unsupported algorithm"));
+        }
+
+        let mut credential = None;
+        let mut signed_headers = None;
+        let mut signature = None;
+        for part in rest.split(',') {
+            match part.trim().split_once('=') {
+                Some(("Credential", v)) => credential = Some(v),
+                Some(("SignedHeaders", v)) => signed_headers = Some(v),
+                Some(("Signature", v)) => signature = Some(v),
+                _ => return Err(invalid("unknown field")),
+            }
+        }
+
+        let credential = credential.ok_or(invalid("missing Credential"))?;
+        let mut scope = credential.splitn(4, '/');
+        let (Some(secret_id), Some(date), Some(service), Some(TERMINATOR)) =
+            (scope.next(), scope.next(), scope.next(), scope.next())
+        else {
+            return Err(invalid("invalid credential scope"));
+        };
+        if secret_id.is_empty() || service.is_empty() {
+            return Err(invalid("invalid credential scope"));
+        }
+
+        let signed_headers: Vec<String> = signed_headers
+            .ok_or(invalid("missing SignedHeaders"))?
+            .split(';')
+            .map(|h| h.trim().to_ascii_lowercase())
+            .collect();
+        for required in ["content-type", "host"] {
+            if !signed_headers.iter().any(|h| h == required) {
+                return Err(invalid("content-type and host must be signed"));
+            }
+        }
+
+        Ok(Self {
+            secret_id: secret_id.into(),
+            date: date.into(),
+            service: service.into(),
+            signed_headers,
+            signature: signature.ok_or(invalid("missing Signature"))?.into(),
+        })
+    }
+}
+
+/// 服务端校验 TC3-HMAC-SHA256 签名, 比如内部网关或本地的测试服务器
+///
+/// `lookup` 根据 SecretId 查找密钥, 密钥带有 `token` 时, 请求的 `X-TC-Token` 必须与之相同
+///
+/// ```
+/// use dnspod_lib::credential::Credential;
+/// use dnspod_lib::signature::Tc3Verifier;
+///
+/// let verifier = Tc3Verifier::new("dnspod", |secret_id: &str| {
+///     (secret_id == "id").then(|| Credential::new("id", "key"))
+/// });
+/// ```
+pub struct Tc3Verifier<F> {
+    service: String,
+    lookup: F,
+    window: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl<F: Fn(&str) -> Option<Credential>> Tc3Verifier<F> {
+    pub fn new(service: impl Into<String>, lookup: F) -> Self {
+        Self {
+            service: service.into(),
+            lookup,
+            window: Duration::minutes(5),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// `X-TC-Timestamp` 与当前时间允许的最大偏差, 默认 5 分钟
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// 当前时间, 默认为系统时间
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// 校验请求, 成功时返回解析后的 Authorization
+    pub fn verify(&self, request: &HttpRequest) -> Result<Tc3Authorization, VerifyError> {
+        let header = |name: &str| {
+            request
+                .headers
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        };
+
+        let authorization = header("Authorization").ok_or(VerifyError::InvalidAuthorization(
+            "missing Authorization".into(),
+        ))?;
+        let authorization = Tc3Authorization::parse(authorization)?;
+        if authorization.service != self.service {
+            return Err(VerifyError::InvalidAuthorization(format!(
+                "service should be {}",
+                self.service
+            )));
+        }
+
+        let timestamp = header("X-TC-Timestamp")
+            .and_then(|t| t.parse::<i64>().ok())
+            .ok_or(VerifyError::SignatureFailure(
+                "missing or invalid X-TC-Timestamp".into(),
+            ))?;
+        let now = self.clock.now().timestamp();
+        // 时间戳来自请求, 不能直接相减, 否则极端值会溢出
+        if now.abs_diff(timestamp) > self.window.num_seconds().unsigned_abs() {
+            return Err(VerifyError::SignatureExpire { timestamp, now });
+        }
+        let datetime =
+            Utc.timestamp_opt(timestamp, 0)
+                .single()
+                .ok_or(VerifyError::SignatureFailure(
+                    "invalid X-TC-Timestamp".into(),
+                ))?;
+        if datetime.date_naive().to_string() != authorization.date {
+            return Err(VerifyError::SignatureFailure(
+                "credential scope date does not match X-TC-Timestamp".into(),
+            ));
+        }
+
+        let credential = (self.lookup)(&authorization.secret_id).ok_or(
+            VerifyError::SecretIdNotFound(authorization.secret_id.clone()),
+        )?;
+        if let Some(ref token) = credential.token {
+            if header("X-TC-Token") != Some(token.as_str()) {
+                return Err(VerifyError::TokenFailure);
+            }
+        }
+
+        let query = request.url.split_once('?').map_or("", |(_, q)| q);
+        let query = query.split_once('#').map_or(query, |(q, _)| q);
+        let mut signer = Tc3Signer::new(&self.service, url_host(&request.url))
+            .method(request.method)
+            .query(query);
+        for name in &authorization.signed_headers {
+            let value = match header(name) {
+                Some(value) => value,
+                // 有些 HTTP 库不会把 Host 放进头部
+                None if name == "host" => url_host(&request.url),
+                None => {
+                    return Err(VerifyError::SignatureFailure(format!(
+                        "signed header `{name}` not found"
+                    )))
+                }
+            };
+            signer = signer.header(name, value);
+        }
+
+        let signature = signer.signature(&request.body, &credential.secret_key, datetime);
+        if !constant_time_eq(signature.as_bytes(), authorization.signature.as_bytes()) {
+            return Err(VerifyError::SignatureFailure(
+                "signature does not match".into(),
+            ));
+        }
+
+        Ok(authorization)
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use super::*;
+    use crate::action::{DeleteRecord, DescribeRecordList};
+    use crate::clock::FixedClock;
+    use crate::transport::Method;
+    use crate::ExtractCommonParams;
+
+    fn datetime() -> DateTime<Utc> {
+        Utc.timestamp_opt(1551113065, 0).unwrap()
+    }
+
+    fn verifier() -> Tc3Verifier<impl Fn(&str) -> Option<Credential>> {
+        Tc3Verifier::new("dnspod", |secret_id: &str| match secret_id {
+            "id" => Some(Credential::new("id", "key")),
+            "tmp-id" => Some(Credential::new("tmp-id", "tmp-key").with_token("token")),
+            _ => None,
+        })
+        .with_clock(FixedClock(datetime() + Duration::seconds(30)))
+    }
+
+    fn request(credential: &Credential, method: Method) -> HttpRequest {
+        let request = DescribeRecordList {
+            Domain: "example.com".into(),
+            Subdomain: Some("www".into()),
+            Keyword: None,
+            ..Default::default()
+        };
+        crate::client::sign(
+            &request,
+            method,
+            "http://127.0.0.1:8080",
+            credential,
+            datetime(),
+            Default::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn verify_signed_request() {
+        let credential = Credential::new("id", "key");
+        let auth = verifier()
+            .verify(&request(&credential, Method::POST))
+            .unwrap();
+        assert_eq!(auth.secret_id, "id");
+        assert_eq!(auth.date, "2019-02-25");
+        assert_eq!(auth.signed_headers, ["content-type", "host", "x-tc-action"]);
+
+        verifier()
+            .verify(&request(&credential, Method::GET))
+            .unwrap();
+
+        let credential = Credential::new("tmp-id", "tmp-key").with_token("token");
+        verifier()
+            .verify(&request(&credential, Method::POST))
+            .unwrap();
+    }
+
+    #[test]
+    fn verify_failures() {
+        let credential = Credential::new("id", "key");
+
+        let mut req = request(&credential, Method::POST);
+        req.body = br#"{"Domain":"evil.com"}"#.to_vec();
+        let err = verifier().verify(&req).unwrap_err();
+        assert_eq!(err.code(), "AuthFailure.SignatureFailure");
+
+        let req = request(&Credential::new("unknown", "key"), Method::POST);
+        let err = verifier().verify(&req).unwrap_err();
+        assert_eq!(err, VerifyError::SecretIdNotFound("unknown".into()));
+
+        let req = request(&Credential::new("id", "wrong-key"), Method::POST);
+        let err = verifier().verify(&req).unwrap_err();
+        assert_eq!(err.code(), "AuthFailure.SignatureFailure");
+
+        let req = request(&Credential::new("tmp-id", "tmp-key"), Method::POST);
+        assert_eq!(
+            verifier().verify(&req).unwrap_err(),
+            VerifyError::TokenFailure
+        );
+
+        let req = request(&credential, Method::POST);
+        let err = verifier()
+            .with_clock(FixedClock(datetime() + Duration::minutes(6)))
+            .verify(&req)
+            .unwrap_err();
+        assert_eq!(err.code(), "AuthFailure.SignatureExpire");
+        for timestamp in [i64::MIN, i64::MAX] {
+            let mut req = request(&credential, Method::POST);
+            req.headers
+                .insert("X-TC-Timestamp".into(), timestamp.to_string());
+            let err = verifier().verify(&req).unwrap_err();
+            assert_eq!(err.code(), "AuthFailure.SignatureExpire");
+        }
+
+        let mut req = request(&credential, Method::POST);
+        req.headers
+            .insert("Authorization".into(), "Basic dXNlcjpwYXNz".into());
+        let err = verifier().verify(&req).unwrap_err();
+        assert_eq!(err.code(), "AuthFailure.InvalidAuthorization");
+
+        let req = request(&credential, Method::POST);
+        let err = Tc3Verifier::new("cvm", |_: &str| Some(credential.clone()))
+            .with_clock(FixedClock(datetime()))
+            .verify(&req)
+            .unwrap_err();
+        assert_eq!(err.code(), "AuthFailure.InvalidAuthorization");
+    }
+
+    #[test]
+    fn verify_action_header_is_signed() {
+        let credential = Credential::new("id", "key");
+        let delete = DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 1,
+        };
+        let mut req = request(&credential, Method::POST);
+        req.headers
+            .insert("X-TC-Action".into(), delete.action().into());
+        let err = verifier().verify(&req).unwrap_err();
+        assert_eq!(err.code(), "AuthFailure.SignatureFailure");
+    }
+
+    #[test]
+    fn parse_authorization() {
+        let auth = Tc3Authorization::parse("TC3-HMAC-SHA256 Credential=AKIDz8krbsJ5yKBZQpn74WFkmLPx3*******/2019-02-25/cvm/tc3_request, SignedHeaders=content-type;host;x-tc-action, Signature=be4f67d323c78ab9acb7395e43c0dbcf822a9cfac32fea2449a7bc7726b770a3").unwrap();
+        assert_eq!(auth.secret_id, "AKIDz8krbsJ5yKBZQpn74WFkmLPx3*******");
+        assert_eq!(auth.service, "cvm");
+
+        assert!(Tc3Authorization::parse("TC3-HMAC-SHA256 Credential=id/2019-02-25/cvm, SignedHeaders=content-type;host, Signature=00").is_err());
+        assert!(Tc3Authorization::parse("TC3-HMAC-SHA256 Credential=id/2019-02-25/cvm/tc3_request, SignedHeaders=host, Signature=00").is_err());
+    }
+}
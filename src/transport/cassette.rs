@@ -0,0 +1,389 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{AsyncTransport, HttpRequest, HttpResponse, Transport};
+use crate::error::{Error, Result};
+
+/// 替换敏感信息的占位符
+const REDACTED: &str = "REDACTED";
+
+/// 需要替换的头部, 不区分大小写
+const SENSITIVE_HEADERS: [&str; 3] = ["Authorization", "X-TC-Token", "Cookie"];
+
+/// 需要替换的 json 字段, 比如 STS 返回的临时密钥
+const SENSITIVE_FIELDS: [&str; 7] = [
+    "SecretId",
+    "SecretKey",
+    "TmpSecretId",
+    "TmpSecretKey",
+    "Token",
+    "SessionToken",
+    "Password",
+];
+
+/// 录制的一次请求和返回结果, 已经替换了密钥、签名等敏感信息
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+    /// json 内容保存为 json, 其它内容保存为字符串
+    pub body: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: Value,
+}
+
+impl From<&HttpRequest> for RecordedRequest {
+    fn from(request: &HttpRequest) -> Self {
+        let method: &'static str = request.method.into();
+        Self {
+            method: method.to_string(),
+            url: request.url.clone(),
+            headers: redact_headers(request.headers.iter()),
+            body: redact_body(&request.body),
+        }
+    }
+}
+
+impl From<&HttpResponse> for RecordedResponse {
+    fn from(response: &HttpResponse) -> Self {
+        Self {
+            status: response.status,
+            headers: redact_headers(response.headers.iter()),
+            body: redact_body(&response.body),
+        }
+    }
+}
+
+impl From<&RecordedResponse> for HttpResponse {
+    fn from(response: &RecordedResponse) -> Self {
+        let body = match response.body {
+            Value::String(ref s) => s.clone(),
+            ref body => body.to_string(),
+        };
+        Self {
+            status: response.status,
+            headers: response
+                .headers
+                .iter()
+                .map(|(k, v)| (k.to_ascii_lowercase(), v.clone()))
+                .collect(),
+            body: body.into_bytes(),
+        }
+    }
+}
+
+impl RecordedRequest {
+    fn action(&self) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("X-TC-Action"))
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn query(&self) -> &str {
+        self.url.split_once('?').map_or("", |(_, q)| q)
+    }
+
+    /// `X-TC-Action`、请求方法、query 和内容都相同. 签名、时间戳和域名不参与比较
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.action() == other.action()
+            && self.method == other.method
+            && self.query() == other.query()
+            && self.body == other.body
+    }
+}
+
+/// 录制好的请求和返回结果, 可以保存为 json 文件
+///
+/// 作为 [`Transport`] 使用时, 按 `X-TC-Action` 和请求内容返回录制的结果, 不发送网络请求.
+/// 相同的请求按录制的顺序返回, 用完之后重复返回最后一个
+///
+/// ```no_run
+/// use dnspod_lib::prelude::*;
+/// use dnspod_lib::transport::Cassette;
+///
+/// let cassette = Cassette::load("tests/cassettes/describe_record_list.json").unwrap();
+/// let client = Client::with_transport("id", "key", cassette);
+/// let res = client.execute(DescribeRecordList {
+///     Domain: "example.com".into(),
+///     Subdomain: None,
+///     Keyword: None,
+///     ..Default::default()
+/// }).unwrap();
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+    #[serde(skip)]
+    used: Mutex<Vec<usize>>,
+}
+
+impl Clone for Cassette {
+    fn clone(&self) -> Self {
+        Self::new(self.interactions.clone())
+    }
+}
+
+impl Cassette {
+    pub fn new(interactions: Vec<Interaction>) -> Self {
+        Self {
+            interactions,
+            used: Default::default(),
+        }
+    }
+
+    /// 从 json 文件读取
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let content = std::fs::read(path)?;
+        serde_json::from_slice(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// 保存为 json 文件
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let content = serde_json::to_vec_pretty(self)?;
+        std::fs::write(path, content)
+    }
+
+    fn replay(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let request = RecordedRequest::from(request);
+        let mut used = self.used.lock().unwrap();
+        let matched: Vec<_> = (0..self.interactions.len())
+            .filter(|&i| self.interactions[i].request.matches(&request))
+            .collect();
+        let index = matched
+            .iter()
+            .find(|i| !used.contains(i))
+            .or(matched.last())
+            .copied()
+            .ok_or_else(|| {
+                Error::Transport(
+                    format!(
+                        "Cassette: no interaction for {} {}",
+                        request.action().unwrap_or_default(),
+                        request.body
+                    )
+                    .into(),
+                )
+            })?;
+        used.push(index);
+        Ok((&self.interactions[index].response).into())
+    }
+}
+
+impl Transport for Cassette {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        self.replay(&request)
+    }
+}
+
+impl AsyncTransport for Cassette {
+    fn send(&self, request: HttpRequest) -> impl Future<Output = Result<HttpResponse>> + Send {
+        std::future::ready(self.replay(&request))
+    }
+}
+
+/// 通过 `inner` 发送请求, 并录制请求和返回结果
+///
+/// 录制时会替换 `Authorization`、`X-TC-Token` 和返回结果中的临时密钥等敏感信息,
+/// 得到的 [`Cassette`] 可以提交到代码仓库, 在没有密钥的 CI 中回放
+///
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn main() {
+/// use dnspod_lib::prelude::*;
+/// use dnspod_lib::transport::{Recorder, ReqwestTransport};
+///
+/// let recorder = Recorder::new(ReqwestTransport::default());
+/// let client = Client::with_transport("your-secret-id", "your-secret-key", &recorder);
+/// client.execute(DescribeRecordList {
+///     Domain: "example.com".into(),
+///     Subdomain: None,
+///     Keyword: None,
+///     ..Default::default()
+/// }).unwrap();
+/// recorder.save("tests/cassettes/describe_record_list.json").unwrap();
+/// # }
+/// # #[cfg(not(feature = "blocking"))] fn main() {}
+/// ```
+#[derive(Debug, Default)]
+pub struct Recorder<T> {
+    inner: T,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl<T> Recorder<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            interactions: Default::default(),
+        }
+    }
+
+    /// 目前为止录制的结果
+    pub fn cassette(&self) -> Cassette {
+        Cassette::new(self.interactions.lock().unwrap().clone())
+    }
+
+    /// 保存为 json 文件
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.cassette().save(path)
+    }
+
+    fn record(&self, request: RecordedRequest, response: &Result<HttpResponse>) {
+        if let Ok(response) = response {
+            self.interactions.lock().unwrap().push(Interaction {
+                request,
+                response: response.into(),
+            });
+        }
+    }
+}
+
+impl<T: Transport> Transport for Recorder<T> {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let recorded = RecordedRequest::from(&request);
+        let response = self.inner.send(request);
+        self.record(recorded, &response);
+        response
+    }
+}
+
+impl<T: AsyncTransport + Sync> AsyncTransport for Recorder<T> {
+    fn send(&self, request: HttpRequest) -> impl Future<Output = Result<HttpResponse>> + Send {
+        let recorded = RecordedRequest::from(&request);
+        async move {
+            let response = self.inner.send(request).await;
+            self.record(recorded, &response);
+            response
+        }
+    }
+}
+
+fn redact_headers<'a>(
+    headers: impl Iterator<Item = (&'a String, &'a String)>,
+) -> BTreeMap<String, String> {
+    headers
+        .map(|(k, v)| {
+            let v = if SENSITIVE_HEADERS.iter().any(|h| h.eq_ignore_ascii_case(k)) {
+                REDACTED.to_string()
+            } else {
+                v.clone()
+            };
+            (k.clone(), v)
+        })
+        .collect()
+}
+
+fn redact_body(body: &[u8]) -> Value {
+    match serde_json::from_slice(body) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value
+        }
+        Err(_) => Value::String(String::from_utf8_lossy(body).into_owned()),
+    }
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                if SENSITIVE_FIELDS.contains(&k.as_str()) && !v.is_null() {
+                    *v = Value::String(REDACTED.into());
+                } else {
+                    redact_value(v);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::{DeleteRecord, DescribeRecordList};
+    use crate::client::Client;
+    use crate::credential::Credential;
+    use crate::transport::MemoryTransport;
+
+    fn list() -> DescribeRecordList {
+        DescribeRecordList {
+            Domain: "example.com".into(),
+            Subdomain: Some("www".into()),
+            Keyword: None,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let transport = MemoryTransport::new();
+        transport.push_json(r#"{"Response": {"RecordCountInfo": {"SubdomainCount": 1, "TotalCount": 1, "ListCount": 1}, "RecordList": [], "RequestId": "1"}}"#);
+        transport.push_json(r#"{"Response": {"RequestId": "2"}}"#);
+        transport.push_json(r#"{"Response": {"Error": {"Code": "ResourceNotFound.NoDataOfRecord", "Message": ""}, "RequestId": "3"}}"#);
+
+        let recorder = Recorder::new(&transport);
+        let client =
+            Client::with_provider(Credential::new("id", "key").with_token("token"), &recorder);
+        client.execute(list()).unwrap();
+        let delete = DeleteRecord {
+            Domain: "example.com".into(),
+            RecordId: 1,
+        };
+        client.execute(delete.clone()).unwrap();
+        client.execute(list()).unwrap_err();
+
+        let cassette = recorder.cassette();
+        let json = serde_json::to_string(&cassette).unwrap();
+        assert!(!json.contains("TC3-HMAC-SHA256"));
+        assert!(!json.contains(r#""token""#));
+        let cassette: Cassette = serde_json::from_str(&json).unwrap();
+
+        // 回放时签名不同, 相同的请求按录制顺序返回
+        let client = Client::with_transport("other-id", "other-key", cassette);
+        assert_eq!(client.execute(list()).unwrap().RequestId, "1");
+        assert_eq!(client.execute(delete).unwrap().RequestId, "2");
+        let err = client.execute(list()).unwrap_err();
+        assert_eq!(err.request_id(), Some("3"));
+        let err = client.execute(list()).unwrap_err();
+        assert_eq!(err.request_id(), Some("3"));
+
+        let err = client
+            .execute(DeleteRecord {
+                Domain: "example.com".into(),
+                RecordId: 2,
+            })
+            .unwrap_err();
+        assert!(matches!(err, Error::Transport(_)));
+    }
+
+    #[test]
+    fn redact_secrets() {
+        let body = br#"{"Response": {"Credentials": {"TmpSecretId": "AKID", "TmpSecretKey": "key", "Token": "token"}, "ExpiredTime": 1}}"#;
+        assert_eq!(
+            redact_body(body),
+            serde_json::json!({"Response": {"Credentials": {"TmpSecretId": "REDACTED", "TmpSecretKey": "REDACTED", "Token": "REDACTED"}, "ExpiredTime": 1}})
+        );
+        assert_eq!(redact_body(b"not json"), Value::String("not json".into()));
+    }
+}
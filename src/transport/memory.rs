@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Mutex;
+
+use super::{AsyncTransport, HttpRequest, HttpResponse, Transport};
+use crate::error::{Error, Result};
+
+type Handler = Box<dyn Fn(&HttpRequest) -> Result<HttpResponse> + Send + Sync>;
+
+/// 不发送网络请求的 [`Transport`], 用于测试
+///
+/// 按顺序返回预先放入的结果, 或者交给自定义的 handler 处理, 并记录收到的所有请求
+///
+/// ```
+/// use dnspod_lib::prelude::*;
+/// use dnspod_lib::client::Client;
+/// use dnspod_lib::transport::MemoryTransport;
+///
+/// let transport = MemoryTransport::new();
+/// transport.push_json(r#"{"Response": {"RequestId": "1"}}"#);
+///
+/// let client = Client::with_transport("id", "key", &transport);
+/// let res = client.execute(DeleteRecord { Domain: "example.com".into(), RecordId: 1 }).unwrap();
+/// assert_eq!(res.RequestId, "1");
+/// assert_eq!(transport.requests()[0].headers["X-TC-Action"], "DeleteRecord");
+/// ```
+#[derive(Default)]
+pub struct MemoryTransport {
+    handler: Option<Handler>,
+    responses: Mutex<VecDeque<HttpResponse>>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl MemoryTransport {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// 由 handler 生成每个请求的返回结果
+    pub fn with_handler(
+        handler: impl Fn(&HttpRequest) -> Result<HttpResponse> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            handler: Some(Box::new(handler)),
+            ..Default::default()
+        }
+    }
+
+    /// 放入一个返回结果
+    pub fn push_response(&self, response: HttpResponse) {
+        self.responses.lock().unwrap().push_back(response);
+    }
+
+    /// 放入一个状态码为 200 的 json 返回结果
+    pub fn push_json(&self, body: impl Into<String>) {
+        self.push_response(HttpResponse::ok(body.into()));
+    }
+
+    /// 目前为止收到的所有请求
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let res = match self.responses.lock().unwrap().pop_front() {
+            Some(res) => Ok(res),
+            None => match self.handler {
+                Some(ref handler) => handler(&request),
+                None => Err(Error::Transport("MemoryTransport: no response left".into())),
+            },
+        };
+        self.requests.lock().unwrap().push(request);
+        res
+    }
+}
+
+impl std::fmt::Debug for MemoryTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryTransport")
+            .field("responses", &self.responses)
+            .field("requests", &self.requests)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        self.respond(request)
+    }
+}
+
+impl AsyncTransport for MemoryTransport {
+    fn send(&self, request: HttpRequest) -> impl Future<Output = Result<HttpResponse>> + Send {
+        std::future::ready(self.respond(request))
+    }
+}
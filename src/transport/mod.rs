@@ -0,0 +1,141 @@
+//! HTTP 传输层
+//!
+//! Client 只负责签名和解析返回结果, 真正的 HTTP 请求交给 [`Transport`] / [`AsyncTransport`] 完成
+//!
+//! + `blocking` feature: [`ReqwestTransport`]
+//! + `async` feature: [`AsyncReqwestTransport`]
+//! + `ureq` feature: [`UreqTransport`]
+//! + [`MemoryTransport`] 不发送网络请求, 用于测试
+//! + [`Recorder`] 录制真实的请求和返回结果, [`Cassette`] 回放录制的结果
+
+mod cassette;
+mod memory;
+#[cfg(any(feature = "blocking", feature = "async"))]
+mod reqwest;
+#[cfg(feature = "ureq")]
+mod ureq;
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use literal_enum::LiteralEnum;
+
+#[cfg(feature = "async")]
+pub use self::reqwest::AsyncReqwestTransport;
+#[cfg(feature = "blocking")]
+pub use self::reqwest::ReqwestTransport;
+#[cfg(feature = "ureq")]
+pub use self::ureq::UreqTransport;
+pub use cassette::{Cassette, Interaction, RecordedRequest, RecordedResponse, Recorder};
+pub use memory::MemoryTransport;
+
+use crate::error::Result;
+
+/// HTTP 请求方法
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, LiteralEnum)]
+pub enum Method {
+    GET,
+    #[default]
+    POST,
+}
+
+/// 签名后待发送的请求
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// 生成等价的 curl 命令, 方便分享和调试只读的请求
+    ///
+    /// 注意: 命令中包含签名, 在签名过期 (5 分钟) 之前可以重放
+    pub fn to_curl(&self) -> String {
+        let quote = |s: &str| format!("'{}'", s.replace('\'', r"'\''"));
+        let method: &'static str = self.method.into();
+
+        let mut headers: Vec<_> = self.headers.iter().collect();
+        headers.sort();
+
+        let mut curl = format!("curl -X {method} {}", quote(&self.url));
+        for (k, v) in headers {
+            curl.push_str(&format!(" -H {}", quote(&format!("{k}: {v}"))));
+        }
+        if !self.body.is_empty() {
+            curl.push_str(&format!(
+                " -d {}",
+                quote(&String::from_utf8_lossy(&self.body))
+            ));
+        }
+        curl
+    }
+}
+
+/// 服务器返回的状态码、头部与内容
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    /// 头部名称统一为小写
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// 状态码为 200 的返回结果
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status: 200,
+            headers: Default::default(),
+            body: body.into(),
+        }
+    }
+
+    /// 添加一个头部
+    pub fn with_header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.insert(name.to_ascii_lowercase(), value.into());
+        self
+    }
+
+    /// 获取头部, 不区分大小写
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+}
+
+/// 同步发送 HTTP 请求
+pub trait Transport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+/// 异步发送 HTTP 请求
+pub trait AsyncTransport {
+    fn send(&self, request: HttpRequest) -> impl Future<Output = Result<HttpResponse>> + Send;
+}
+
+impl<T: Transport + ?Sized> Transport for &T {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        (**self).send(request)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for std::sync::Arc<T> {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        (**self).send(request)
+    }
+}
+
+impl<T: AsyncTransport + Sync + ?Sized> AsyncTransport for &T {
+    fn send(&self, request: HttpRequest) -> impl Future<Output = Result<HttpResponse>> + Send {
+        (**self).send(request)
+    }
+}
+
+impl<T: AsyncTransport + Send + Sync + ?Sized> AsyncTransport for std::sync::Arc<T> {
+    fn send(&self, request: HttpRequest) -> impl Future<Output = Result<HttpResponse>> + Send {
+        (**self).send(request)
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "async")]
+use super::AsyncTransport;
+#[cfg(feature = "blocking")]
+use super::Transport;
+use super::{HttpRequest, HttpResponse, Method};
+use crate::error::{Error, Result};
+
+fn header_map(request: &HttpRequest) -> Result<reqwest::header::HeaderMap> {
+    reqwest::header::HeaderMap::try_from(&request.headers)
+        .map_err(|e| Error::Transport(Box::new(e)))
+}
+
+fn response_headers(headers: &reqwest::header::HeaderMap) -> HashMap<String, String> {
+    headers
+        .iter()
+        .filter_map(|(k, v)| Some((k.as_str().to_string(), v.to_str().ok()?.to_string())))
+        .collect()
+}
+
+fn method(request: &HttpRequest) -> reqwest::Method {
+    match request.method {
+        Method::GET => reqwest::Method::GET,
+        Method::POST => reqwest::Method::POST,
+    }
+}
+
+/// 基于 `reqwest::blocking::Client` 的 [`Transport`]
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(feature = "blocking")]
+impl ReqwestTransport {
+    pub fn new(client: reqwest::blocking::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let res = self
+            .client
+            .request(method(&request), &request.url)
+            .headers(header_map(&request)?)
+            .body(request.body)
+            .send()
+            .map_err(|e| Error::Transport(e.into()))?;
+
+        let status = res.status().as_u16();
+        let headers = response_headers(res.headers());
+        let body = res.bytes().map_err(|e| Error::Transport(e.into()))?;
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+}
+
+/// 基于 `reqwest::Client` 的 [`AsyncTransport`]
+#[cfg(feature = "async")]
+#[derive(Debug, Clone, Default)]
+pub struct AsyncReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl AsyncReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for AsyncReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let res = self
+            .client
+            .request(method(&request), &request.url)
+            .headers(header_map(&request)?)
+            .body(request.body)
+            .send()
+            .await
+            .map_err(|e| Error::Transport(e.into()))?;
+
+        let status = res.status().as_u16();
+        let headers = response_headers(res.headers());
+        let body = res.bytes().await.map_err(|e| Error::Transport(e.into()))?;
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+}
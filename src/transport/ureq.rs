@@ -0,0 +1,59 @@
+use std::io::Read;
+
+use super::{HttpRequest, HttpResponse, Transport};
+use crate::error::{Error, Result};
+
+/// 基于 `ureq::Agent` 的 [`Transport`]
+#[derive(Debug, Clone)]
+pub struct UreqTransport {
+    agent: ureq::Agent,
+}
+
+impl UreqTransport {
+    pub fn new(agent: ureq::Agent) -> Self {
+        Self { agent }
+    }
+}
+
+impl Default for UreqTransport {
+    fn default() -> Self {
+        Self::new(ureq::Agent::new())
+    }
+}
+
+impl Transport for UreqTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let method: &'static str = request.method.into();
+        let mut req = self.agent.request(method, &request.url);
+        for (k, v) in &request.headers {
+            req = req.set(k, v);
+        }
+
+        let res = match req.send_bytes(&request.body) {
+            Ok(res) => res,
+            // 非 2xx 的状态码也把内容交给调用方解析
+            Err(ureq::Error::Status(_, res)) => res,
+            Err(e) => return Err(Error::Transport(Box::new(e))),
+        };
+
+        let status = res.status();
+        let headers = res
+            .headers_names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = res.header(&name)?.to_string();
+                Some((name.to_ascii_lowercase(), value))
+            })
+            .collect();
+        let mut body = Vec::new();
+        res.into_reader()
+            .read_to_end(&mut body)
+            .map_err(|e| Error::Transport(Box::new(e)))?;
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
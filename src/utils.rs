@@ -41,3 +41,84 @@ where
 {
     serializer.serialize_str(input.timestamp().to_string().as_str())
 }
+
+/// 取出 url 中的 host (包括端口), 作为签名时的 `host` 头部
+///
+/// ```
+/// # use dnspod_lib::utils::url_host;
+/// assert_eq!(url_host("https://sts.tencentcloudapi.com"), "sts.tencentcloudapi.com");
+/// assert_eq!(url_host("http://127.0.0.1:8080/"), "127.0.0.1:8080");
+/// ```
+pub fn url_host(url: &str) -> &str {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let end = url.find(['/', '?', '#']).unwrap_or(url.len());
+    &url[..end]
+}
+
+/// 按 RFC3986 编码, 除了 `A-Z a-z 0-9 - _ . ~` 之外的字符都编码为大写的 `%XX`
+///
+/// ```
+/// # use dnspod_lib::utils::percent_encode;
+/// assert_eq!(percent_encode("a b/c~"), "a%20b%2Fc~");
+/// assert_eq!(percent_encode("默认"), "%E9%BB%98%E8%AE%A4");
+/// ```
+pub fn percent_encode(input: &str) -> String {
+    let mut s = String::with_capacity(input.len());
+    for b in input.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                s.push(b as char)
+            }
+            _ => s.push_str(&format!("%{b:02X}")),
+        }
+    }
+    s
+}
+
+/// 把 json 格式的请求参数转换为 GET 请求的查询字符串
+///
+/// 嵌套的参数按 `Filters.0.Name` 的格式展开, 值为 `null` 的参数会被忽略, 参数名按升序排列.
+/// `body` 不是 json 时返回 [`Error::Serialize`](crate::Error::Serialize)
+///
+/// ```
+/// # use dnspod_lib::utils::canonical_query_string;
+/// let body = br#"{"Limit": 10, "Filters": [{"Name": "instance-name", "Values": ["a b"]}], "Keyword": null}"#;
+/// assert_eq!(
+///     canonical_query_string(body).unwrap(),
+///     "Filters.0.Name=instance-name&Filters.0.Values.0=a%20b&Limit=10"
+/// );
+/// assert!(canonical_query_string(b"Limit=10").is_err());
+/// ```
+pub fn canonical_query_string(body: &[u8]) -> crate::Result<String> {
+    fn flatten(prefix: String, value: &serde_json::Value, pairs: &mut Vec<(String, String)>) {
+        let key = |k: &dyn std::fmt::Display| match prefix.is_empty() {
+            true => k.to_string(),
+            false => format!("{prefix}.{k}"),
+        };
+        match value {
+            serde_json::Value::Null => {}
+            serde_json::Value::Object(map) => {
+                for (k, v) in map {
+                    flatten(key(k), v, pairs);
+                }
+            }
+            serde_json::Value::Array(list) => {
+                for (i, v) in list.iter().enumerate() {
+                    flatten(key(&i), v, pairs);
+                }
+            }
+            serde_json::Value::String(s) => pairs.push((prefix, s.clone())),
+            v => pairs.push((prefix, v.to_string())),
+        }
+    }
+
+    let value = serde_json::from_slice(body).map_err(crate::Error::Serialize)?;
+    let mut pairs = vec![];
+    flatten(String::new(), &value, &mut pairs);
+    pairs.sort();
+    Ok(pairs
+        .iter()
+        .map(|(k, v)| format!("{}={}", percent_encode(k), percent_encode(v)))
+        .collect::<Vec<_>>()
+        .join("&"))
+}